@@ -1,10 +1,22 @@
+#[cfg(feature = "websocket")]
+use std::collections::VecDeque;
+
 use super::err::Result;
 use serde_derive::Deserialize;
 use serde_derive::Serialize;
 
 use crate::err::JellyfinError;
+#[cfg(feature = "websocket")]
+use crate::socket::JellyfinSocket;
+#[cfg(feature = "websocket")]
+use crate::socket::ServerMessage;
+#[cfg(feature = "websocket")]
+use crate::socket::Subscription;
 use crate::JellyfinClient;
 
+#[cfg(feature = "websocket")]
+const BACKFILL_PAGE_SIZE: u32 = 100;
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ActivityLogEntry {
@@ -33,7 +45,7 @@ struct GetActivityLogEntriesQuery {
     start_index: Option<u32>,
     limit: Option<u32>,
     min_date: Option<String>,
-    has_user_id: bool,
+    has_user_id: Option<bool>,
 }
 
 impl JellyfinClient {
@@ -43,6 +55,17 @@ impl JellyfinClient {
         limit: Option<u32>,
         min_date: Option<String>,
         has_user_id: bool,
+    ) -> Result<ActivityLogEntries> {
+        self.query_activity_log_entries(start_index, limit, min_date, Some(has_user_id))
+            .await
+    }
+
+    async fn query_activity_log_entries(
+        &self,
+        start_index: Option<u32>,
+        limit: Option<u32>,
+        min_date: Option<String>,
+        has_user_id: Option<bool>,
     ) -> Result<ActivityLogEntries> {
         let req = self
             .client
@@ -66,3 +89,193 @@ impl JellyfinClient {
         Ok(req.json().await?)
     }
 }
+
+/// Client side filters for `watch_activity_log`. Empty lists match everything.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ActivityLogFilter {
+    pub severities: Vec<String>,
+    pub types: Vec<String>,
+    pub user_ids: Vec<String>,
+}
+
+impl ActivityLogFilter {
+    pub fn matches(&self, entry: &ActivityLogEntry) -> bool {
+        (self.severities.is_empty() || self.severities.contains(&entry.severity))
+            && (self.types.is_empty() || self.types.contains(&entry.r#type))
+            && (self.user_ids.is_empty() || self.user_ids.contains(&entry.user_id))
+    }
+}
+
+/// Orders, de-duplicates and filters entries for `ActivityLogWatcher`.
+#[cfg(feature = "websocket")]
+#[derive(Debug, Default)]
+struct ActivityLogQueue {
+    filter: ActivityLogFilter,
+    last_id: Option<u32>,
+    pending: VecDeque<ActivityLogEntry>,
+}
+
+#[cfg(feature = "websocket")]
+impl ActivityLogQueue {
+    /// Queues the entries logged after `last_id` that match the filter.
+    fn push(&mut self, mut entries: Vec<ActivityLogEntry>) {
+        entries.sort_by_key(|entry| entry.id);
+        for entry in entries {
+            if self.last_id.is_some_and(|last_id| entry.id <= last_id) {
+                continue;
+            }
+            self.last_id = Some(entry.id);
+            if self.filter.matches(&entry) {
+                self.pending.push_back(entry);
+            }
+        }
+    }
+}
+
+/// A live tail of the activity log, created by `watch_activity_log`.
+#[cfg(feature = "websocket")]
+#[derive(Debug)]
+pub struct ActivityLogWatcher {
+    client: JellyfinClient,
+    socket: JellyfinSocket,
+    queue: ActivityLogQueue,
+    /// Set when the socket reconnects, until the entries missed meanwhile have been fetched
+    backfill_pending: bool,
+}
+
+#[cfg(feature = "websocket")]
+impl ActivityLogWatcher {
+    /// Receives the next entry matching the filter, in the order they were logged.
+    /// Returns `None` if the underlying socket has stopped.
+    pub async fn recv(&mut self) -> Option<Result<ActivityLogEntry>> {
+        loop {
+            if let Some(entry) = self.queue.pending.pop_front() {
+                return Some(Ok(entry));
+            }
+
+            let entries = match self.socket.recv().await? {
+                Ok(ServerMessage::Connected) => {
+                    self.backfill_pending = true;
+                    Vec::new()
+                }
+                Ok(ServerMessage::ActivityLogEntry(entries)) => entries,
+                Ok(_) => continue,
+                Err(e) => return Some(Err(e)),
+            };
+            // Live entries are only queued once the gap before them is filled, otherwise `last_id`
+            // would move past it. If the backfill fails they are dropped, the next backfill fetches them.
+            if self.backfill_pending {
+                match self.backfill().await {
+                    Ok(missed) => {
+                        self.backfill_pending = false;
+                        self.queue.push(missed);
+                    }
+                    Err(e) => return Some(Err(e)),
+                }
+            }
+            self.queue.push(entries);
+        }
+    }
+
+    /// Fetches every entry logged after `last_id`.
+    async fn backfill(&self) -> Result<Vec<ActivityLogEntry>> {
+        let Some(last_id) = self.queue.last_id else {
+            return Ok(Vec::new());
+        };
+
+        let mut entries = Vec::new();
+        let mut start_index = 0;
+        loop {
+            let page = self
+                .client
+                .query_activity_log_entries(Some(start_index), Some(BACKFILL_PAGE_SIZE), None, None)
+                .await?;
+            let reached_last = page.items.iter().any(|entry| entry.id <= last_id);
+            start_index += page.items.len() as u32;
+            let exhausted = page.items.is_empty() || start_index >= page.total_record_count;
+            entries.extend(page.items.into_iter().filter(|entry| entry.id > last_id));
+            if reached_last || exhausted {
+                return Ok(entries);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "websocket")]
+impl JellyfinClient {
+    /// Subscribes to new activity log entries as they are logged.
+    /// Entries missed while the socket was reconnecting are fetched and yielded in order.
+    pub async fn watch_activity_log(&self, filter: ActivityLogFilter) -> Result<ActivityLogWatcher> {
+        let latest = self
+            .query_activity_log_entries(Some(0), Some(1), None, None)
+            .await?;
+        let socket = self.connect_socket().await?;
+        socket.subscribe(Subscription::ActivityLogEntry, 0, 1000)?;
+
+        Ok(ActivityLogWatcher {
+            client: self.clone(),
+            socket,
+            queue: ActivityLogQueue {
+                filter,
+                last_id: latest.items.first().map(|entry| entry.id),
+                pending: VecDeque::new(),
+            },
+            backfill_pending: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn entry(id: u32, severity: &str, user_id: &str) -> ActivityLogEntry {
+        ActivityLogEntry {
+            id,
+            severity: severity.to_string(),
+            r#type: "SessionStarted".to_string(),
+            user_id: user_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn matches_filter() {
+        let filter = ActivityLogFilter {
+            severities: vec!["Warning".to_string(), "Error".to_string()],
+            user_ids: vec!["a".to_string()],
+            ..Default::default()
+        };
+
+        assert!(ActivityLogFilter::default().matches(&entry(1, "Information", "b")));
+        assert!(filter.matches(&entry(1, "Error", "a")));
+        assert!(!filter.matches(&entry(1, "Information", "a")));
+        assert!(!filter.matches(&entry(1, "Error", "b")));
+    }
+
+    #[cfg(feature = "websocket")]
+    #[test]
+    fn queues_new_entries_in_order() {
+        let mut queue = ActivityLogQueue {
+            filter: ActivityLogFilter {
+                severities: vec!["Error".to_string()],
+                ..Default::default()
+            },
+            last_id: Some(10),
+            ..Default::default()
+        };
+
+        queue.push(vec![
+            entry(13, "Error", "a"),
+            entry(9, "Error", "a"),
+            entry(11, "Error", "a"),
+            entry(12, "Information", "a"),
+            entry(10, "Error", "a"),
+        ]);
+        queue.push(vec![entry(13, "Error", "a"), entry(14, "Error", "a")]);
+
+        let ids: Vec<u32> = queue.pending.iter().map(|entry| entry.id).collect();
+        assert_eq!(ids, vec![11, 13, 14]);
+        assert_eq!(queue.last_id, Some(14));
+    }
+}
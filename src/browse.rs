@@ -0,0 +1,138 @@
+use super::err::Result;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use url::Url;
+
+use crate::err::JellyfinError;
+use crate::items::MediaItem;
+use crate::items::MediaItems;
+use crate::JellyfinClient;
+
+/// Paging and filter options for browsing artists, genres, studios, persons and years.
+/// Empty lists don't filter anything.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BrowseQuery {
+    pub user_id: Option<String>,
+    pub parent_id: Option<String>,
+    pub start_index: Option<u32>,
+    pub limit: Option<u32>,
+    pub search_term: Option<String>,
+    pub name_starts_with: Option<String>,
+    pub is_favorite: Option<bool>,
+    /// Only count items of these types, ie. "MusicAlbum"
+    #[serde(skip_serializing_if = "Vec::is_empty", serialize_with = "crate::comma_separated")]
+    pub include_item_types: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", serialize_with = "crate::comma_separated")]
+    pub exclude_item_types: Vec<String>,
+    /// Only used by `get_persons`, ie. "Actor" or "Director"
+    #[serde(skip_serializing_if = "Vec::is_empty", serialize_with = "crate::comma_separated")]
+    pub person_types: Vec<String>,
+    /// ie. "SortName" or "ProductionYear"
+    #[serde(skip_serializing_if = "Vec::is_empty", serialize_with = "crate::comma_separated")]
+    pub sort_by: Vec<String>,
+    /// "Ascending" or "Descending"
+    pub sort_order: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetByNameQuery {
+    user_id: Option<String>,
+}
+
+impl JellyfinClient {
+    pub async fn get_artists(&self, query: BrowseQuery) -> Result<MediaItems> {
+        self.browse("Artists", query).await
+    }
+
+    pub async fn get_album_artists(&self, query: BrowseQuery) -> Result<MediaItems> {
+        self.browse("Artists/AlbumArtists", query).await
+    }
+
+    pub async fn get_genres(&self, query: BrowseQuery) -> Result<MediaItems> {
+        self.browse("Genres", query).await
+    }
+
+    pub async fn get_music_genres(&self, query: BrowseQuery) -> Result<MediaItems> {
+        self.browse("MusicGenres", query).await
+    }
+
+    pub async fn get_studios(&self, query: BrowseQuery) -> Result<MediaItems> {
+        self.browse("Studios", query).await
+    }
+
+    pub async fn get_persons(&self, query: BrowseQuery) -> Result<MediaItems> {
+        self.browse("Persons", query).await
+    }
+
+    pub async fn get_years(&self, query: BrowseQuery) -> Result<MediaItems> {
+        self.browse("Years", query).await
+    }
+
+    pub async fn get_artist_by_name(&self, name: &str, user_id: Option<String>) -> Result<MediaItem> {
+        self.get_by_name("Artists", name, user_id).await
+    }
+
+    pub async fn get_genre_by_name(&self, name: &str, user_id: Option<String>) -> Result<MediaItem> {
+        self.get_by_name("Genres", name, user_id).await
+    }
+
+    pub async fn get_music_genre_by_name(&self, name: &str, user_id: Option<String>) -> Result<MediaItem> {
+        self.get_by_name("MusicGenres", name, user_id).await
+    }
+
+    pub async fn get_studio_by_name(&self, name: &str, user_id: Option<String>) -> Result<MediaItem> {
+        self.get_by_name("Studios", name, user_id).await
+    }
+
+    pub async fn get_person_by_name(&self, name: &str, user_id: Option<String>) -> Result<MediaItem> {
+        self.get_by_name("Persons", name, user_id).await
+    }
+
+    pub async fn get_year(&self, year: i64, user_id: Option<String>) -> Result<MediaItem> {
+        self.get_by_name("Years", &year.to_string(), user_id).await
+    }
+
+    async fn browse(&self, path: &str, query: BrowseQuery) -> Result<MediaItems> {
+        let req = self
+            .client
+            .get(format!("{}{}", self.url, path))
+            .query(&query)
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    async fn get_by_name(&self, path: &str, name: &str, user_id: Option<String>) -> Result<MediaItem> {
+        // Names can contain '/' and other reserved characters, so they are pushed as an encoded segment.
+        let mut url = Url::parse(&format!("{}{}", self.url, path))?;
+        url.path_segments_mut()
+            .map_err(|_| url::ParseError::RelativeUrlWithCannotBeABaseBase)?
+            .push(name);
+
+        let req = self
+            .client
+            .get(url)
+            .query(&GetByNameQuery { user_id })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+}
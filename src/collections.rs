@@ -0,0 +1,141 @@
+use super::err::Result;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+use crate::err::JellyfinError;
+use crate::items::MediaItems;
+use crate::JellyfinClient;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct CollectionCreationResult {
+    pub id: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct CreateCollectionQuery {
+    name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty", serialize_with = "crate::comma_separated")]
+    ids: Vec<String>,
+    parent_id: Option<String>,
+    is_locked: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct CollectionItemsQuery {
+    #[serde(serialize_with = "crate::comma_separated")]
+    ids: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetCollectionsQuery {
+    include_item_types: String,
+    recursive: bool,
+    parent_id: Option<String>,
+    start_index: Option<u32>,
+    limit: Option<u32>,
+}
+
+impl JellyfinClient {
+    /// Creates a collection (box set).
+    /// * `ids` The items to start the collection with
+    /// * `is_locked` Stop the server from changing the collection's metadata
+    pub async fn create_collection(
+        &self,
+        name: &str,
+        ids: Vec<String>,
+        parent_id: Option<String>,
+        is_locked: bool,
+    ) -> Result<CollectionCreationResult> {
+        let req = self
+            .client
+            .post(format!("{}Collections", self.url))
+            .query(&CreateCollectionQuery {
+                name: name.to_string(),
+                ids,
+                parent_id,
+                is_locked,
+            })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    pub async fn add_to_collection(&self, collection_id: &str, ids: Vec<String>) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Collections/{}/Items", self.url, collection_id))
+            .query(&CollectionItemsQuery { ids })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_from_collection(&self, collection_id: &str, ids: Vec<String>) -> Result<()> {
+        let _req = self
+            .client
+            .delete(format!("{}Collections/{}/Items", self.url, collection_id))
+            .query(&CollectionItemsQuery { ids })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Gets the collections a user can see.
+    pub async fn get_collections(
+        &self,
+        user_id: &str,
+        parent_id: Option<String>,
+        start_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<MediaItems> {
+        let req = self
+            .client
+            .get(format!("{}Users/{}/Items", self.url, user_id))
+            .query(&GetCollectionsQuery {
+                include_item_types: "BoxSet".to_string(),
+                recursive: true,
+                parent_id,
+                start_index,
+                limit,
+            })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+}
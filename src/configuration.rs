@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use super::err::Result;
+use serde::de::DeserializeOwned;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use serde_json::Value;
+
+use crate::err::JellyfinError;
+use crate::packages::RepositoryInfo;
+use crate::JellyfinClient;
+
+/// The server wide configuration. Only the common settings are typed, the rest are kept
+/// in `extra` so that `update_configuration` doesn't reset them.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ServerConfiguration {
+    pub server_name: String,
+    #[serde(rename = "UICulture")]
+    pub ui_culture: String,
+    pub preferred_metadata_language: String,
+    pub metadata_country_code: String,
+    pub metadata_path: String,
+    pub cache_path: Option<String>,
+    pub is_startup_wizard_completed: bool,
+    pub log_file_retention_days: i64,
+    pub activity_log_retention_days: Option<i64>,
+    pub min_resume_pct: i64,
+    pub max_resume_pct: i64,
+    pub min_resume_duration_seconds: i64,
+    pub library_monitor_delay: i64,
+    pub enable_folder_view: bool,
+    pub enable_grouping_into_collections: bool,
+    pub display_specials_within_seasons: bool,
+    /// In Mbps, 0 for no limit
+    pub remote_client_bitrate_limit: i64,
+    pub plugin_repositories: Vec<RepositoryInfo>,
+    pub cors_hosts: Vec<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// The "encoding" configuration section.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct EncodingOptions {
+    /// -1 to let ffmpeg decide
+    pub encoding_thread_count: i64,
+    pub transcoding_temp_path: Option<String>,
+    pub encoder_app_path: Option<String>,
+    pub encoder_app_path_display: Option<String>,
+    pub enable_throttling: bool,
+    pub throttle_delay_seconds: i64,
+    /// ie. "none", "vaapi", "qsv" or "nvenc"
+    pub hardware_acceleration_type: String,
+    pub vaapi_device: Option<String>,
+    pub enable_hardware_encoding: bool,
+    pub allow_hevc_encoding: bool,
+    pub hardware_decoding_codecs: Vec<String>,
+    pub enable_tonemapping: bool,
+    pub encoder_preset: Option<String>,
+    pub h264_crf: i64,
+    pub h265_crf: i64,
+    pub down_mix_audio_boost: f64,
+    pub max_muxing_queue_size: i64,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// The "network" configuration section.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct NetworkConfiguration {
+    pub base_url: String,
+    pub enable_https: bool,
+    pub require_https: bool,
+    pub certificate_path: String,
+    pub internal_http_port: i64,
+    pub internal_https_port: i64,
+    pub public_http_port: i64,
+    pub public_https_port: i64,
+    pub auto_discovery: bool,
+    pub enable_remote_access: bool,
+    pub local_network_subnets: Vec<String>,
+    pub local_network_addresses: Vec<String>,
+    pub known_proxies: Vec<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl JellyfinClient {
+    pub async fn get_configuration(&self) -> Result<ServerConfiguration> {
+        let req = self
+            .client
+            .get(format!("{}System/Configuration", self.url))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    pub async fn update_configuration(&self, new_conf: ServerConfiguration) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}System/Configuration", self.url))
+            .json(&new_conf)
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Gets a named configuration section.
+    /// * `key` The name of the section, ie. "encoding", "metadata" or "network"
+    pub async fn get_named_configuration<T: DeserializeOwned>(&self, key: &str) -> Result<T> {
+        let req = self
+            .client
+            .get(format!("{}System/Configuration/{}", self.url, key))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Replaces a named configuration section.
+    /// * `key` The name of the section, ie. "encoding", "metadata" or "network"
+    pub async fn update_named_configuration<T: serde::Serialize>(
+        &self,
+        key: &str,
+        new_conf: T,
+    ) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}System/Configuration/{}", self.url, key))
+            .json(&new_conf)
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_encoding_options(&self) -> Result<EncodingOptions> {
+        self.get_named_configuration("encoding").await
+    }
+
+    pub async fn update_encoding_options(&self, new_conf: EncodingOptions) -> Result<()> {
+        self.update_named_configuration("encoding", new_conf).await
+    }
+
+    pub async fn get_network_configuration(&self) -> Result<NetworkConfiguration> {
+        self.get_named_configuration("network").await
+    }
+
+    pub async fn update_network_configuration(&self, new_conf: NetworkConfiguration) -> Result<()> {
+        self.update_named_configuration("network", new_conf).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn keeps_unknown_keys() {
+        let conf: ServerConfiguration = serde_json::from_str(
+            r#"{"ServerName":"jellyfin","UICulture":"en-US","EnableMetrics":true}"#,
+        )
+        .unwrap();
+        assert_eq!(conf.server_name, "jellyfin");
+        assert_eq!(conf.ui_culture, "en-US");
+        assert_eq!(conf.extra.get("EnableMetrics"), Some(&Value::Bool(true)));
+
+        let json = serde_json::to_value(&conf).unwrap();
+        assert_eq!(json["EnableMetrics"], Value::Bool(true));
+    }
+}
@@ -6,6 +6,12 @@ pub type Result<T> = std::result::Result<T, JellyfinError>;
 pub enum JellyfinError {
     NetworkError(reqwest::Error),
     UrlParseError(url::ParseError),
+    JsonError(serde_json::Error),
+    VersionParseError(String),
+    #[cfg(feature = "websocket")]
+    WebSocketError(Box<tokio_tungstenite::tungstenite::Error>),
+    #[cfg(feature = "websocket")]
+    SocketClosed,
     AuthNotFound
 }
 
@@ -18,6 +24,20 @@ impl fmt::Display for JellyfinError {
             Self::UrlParseError(v) => {
                 write!(f,"{}", v)
             }
+            Self::JsonError(v) => {
+                write!(f,"{}", v)
+            }
+            Self::VersionParseError(v) => {
+                write!(f, "Invalid server version \"{}\".", v)
+            }
+            #[cfg(feature = "websocket")]
+            Self::WebSocketError(v) => {
+                write!(f,"{}", v)
+            }
+            #[cfg(feature = "websocket")]
+            Self::SocketClosed => {
+                write!(f, "Socket closed.")
+            }
             Self::AuthNotFound => {
                 write!(f, "Unauthorized.")
             }
@@ -35,4 +55,17 @@ impl From<url::ParseError> for JellyfinError {
     fn from(value: url::ParseError) -> Self {
         Self::UrlParseError(value)
     }
-}
\ No newline at end of file
+}
+
+impl From<serde_json::Error> for JellyfinError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::JsonError(value)
+    }
+}
+
+#[cfg(feature = "websocket")]
+impl From<tokio_tungstenite::tungstenite::Error> for JellyfinError {
+    fn from(value: tokio_tungstenite::tungstenite::Error) -> Self {
+        Self::WebSocketError(Box::new(value))
+    }
+}
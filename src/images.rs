@@ -0,0 +1,210 @@
+use super::err::Result;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use url::Url;
+
+use crate::err::JellyfinError;
+use crate::JellyfinClient;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ImageType {
+    Primary,
+    Art,
+    Backdrop,
+    Banner,
+    Logo,
+    Thumb,
+    Disc,
+    Box,
+    Screenshot,
+    Menu,
+    Chapter,
+    BoxRear,
+    Profile,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct RemoteImageInfo {
+    pub provider_name: String,
+    pub url: String,
+    pub thumbnail_url: Option<String>,
+    pub height: Option<i64>,
+    pub width: Option<i64>,
+    pub community_rating: Option<f64>,
+    pub vote_count: Option<i64>,
+    pub language: Option<String>,
+    #[serde(rename = "Type")]
+    pub type_field: Option<ImageType>,
+    pub rating_type: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct RemoteImages {
+    pub images: Vec<RemoteImageInfo>,
+    pub total_record_count: u32,
+    /// The providers that returned images
+    pub providers: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ImageProviderInfo {
+    pub name: String,
+    pub supported_images: Vec<ImageType>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetRemoteImagesQuery {
+    #[serde(rename = "Type")]
+    type_field: Option<ImageType>,
+    provider_name: Option<String>,
+    include_all_languages: bool,
+    start_index: Option<u32>,
+    limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct DownloadRemoteImageQuery {
+    #[serde(rename = "Type")]
+    type_field: ImageType,
+    image_url: String,
+}
+
+impl JellyfinClient {
+    /// Builds the url of a chapter's image, images don't require auth so it can be handed to a player directly.
+    /// * `index` The index of the chapter in `MediaItem::chapters`
+    /// * `tag` The `ChapterInfo::image_tag`, used to bust caches when the image changes
+    pub fn chapter_image_url(
+        &self,
+        item_id: &str,
+        index: usize,
+        tag: Option<String>,
+        max_width: Option<u32>,
+    ) -> Result<Url> {
+        let mut url = Url::parse(&format!(
+            "{}Items/{}/Images/Chapter/{}",
+            self.url, item_id, index
+        ))?;
+        if let Some(tag) = tag {
+            url.query_pairs_mut().append_pair("Tag", &tag);
+        }
+        if let Some(max_width) = max_width {
+            url.query_pairs_mut()
+                .append_pair("MaxWidth", &max_width.to_string());
+        }
+        Ok(url)
+    }
+
+    /// Gets the images metadata providers have for an item.
+    /// * `image_type` Only list images of this type, or all types if `None`
+    /// * `provider_name` Only list images from this provider, ie. "TheMovieDb"
+    /// * `language` Only list images in this language, ie. "en", and images without a language.
+    ///   If `None`, the server's metadata language is used instead.
+    ///
+    /// The server can only filter by its own metadata language, so when `language` is set all images
+    /// are fetched and filtered here, and `start_index`, `limit`, `total_record_count` and `providers`
+    /// apply to the filtered images.
+    pub async fn get_remote_images(
+        &self,
+        item_id: &str,
+        image_type: Option<ImageType>,
+        provider_name: Option<String>,
+        language: Option<String>,
+        start_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<RemoteImages> {
+        let req = self
+            .client
+            .get(format!("{}Items/{}/RemoteImages", self.url, item_id))
+            .query(&GetRemoteImagesQuery {
+                type_field: image_type,
+                provider_name,
+                include_all_languages: language.is_some(),
+                start_index: start_index.filter(|_| language.is_none()),
+                limit: limit.filter(|_| language.is_none()),
+            })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        let mut images: RemoteImages = req.json().await?;
+        if let Some(language) = language {
+            images.images.retain(|image| match image.language.as_deref() {
+                Some(image_language) if !image_language.is_empty() => {
+                    image_language.eq_ignore_ascii_case(&language)
+                }
+                _ => true,
+            });
+            images.total_record_count = images.images.len() as u32;
+            images.providers.clear();
+            for image in &images.images {
+                if !images.providers.contains(&image.provider_name) {
+                    images.providers.push(image.provider_name.clone());
+                }
+            }
+            images.images = images
+                .images
+                .into_iter()
+                .skip(start_index.unwrap_or(0) as usize)
+                .take(limit.map_or(usize::MAX, |limit| limit as usize))
+                .collect();
+        }
+        Ok(images)
+    }
+
+    /// Gets the providers that can supply images for an item.
+    pub async fn get_remote_image_providers(&self, item_id: &str) -> Result<Vec<ImageProviderInfo>> {
+        let req = self
+            .client
+            .get(format!("{}Items/{}/RemoteImages/Providers", self.url, item_id))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Downloads a remote image and sets it as the item's image of that type.
+    /// * `image_url` The `url` of a `RemoteImageInfo`
+    pub async fn download_remote_image(
+        &self,
+        item_id: &str,
+        image_type: ImageType,
+        image_url: &str,
+    ) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Items/{}/RemoteImages/Download", self.url, item_id))
+            .query(&DownloadRemoteImageQuery {
+                type_field: image_type,
+                image_url: image_url.to_string(),
+            })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+}
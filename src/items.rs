@@ -1,66 +1,77 @@
+use std::collections::HashMap;
+
+use super::err::Result;
 use serde_derive::Deserialize;
 use serde_derive::Serialize;
 
+use crate::err::JellyfinError;
+use crate::trickplay::TrickplayInfo;
+use crate::user::User;
+use crate::JellyfinClient;
+
+/// The server leaves out fields that are null, so every field falls back to its default when missing.
+/// Fields that are saved back by `update_item` are `Option`, so that a missing value or list is sent back as null.
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct MediaItem {
     pub name: String,    
-    pub original_title: String,    
+    pub original_title: Option<String>,    
     pub server_id: String,    
     pub id: String,    
     pub etag: String,    
     pub source_type: String,    
     pub playlist_item_id: Option<String>,    
-    pub date_created: String,    
+    pub date_created: Option<String>,    
     pub date_last_media_added: String,    
     pub extra_type: String,    
-    pub airs_before_season_number: i64,    
-    pub airs_after_season_number: i64,    
-    pub airs_before_episode_number: i64,    
+    pub airs_before_season_number: Option<i64>,    
+    pub airs_after_season_number: Option<i64>,    
+    pub airs_before_episode_number: Option<i64>,    
     pub can_delete: bool,    
     pub can_download: bool,    
     pub has_subtitles: bool,    
-    pub preferred_metadata_language: String,    
-    pub preferred_metadata_country_code: String,    
+    pub preferred_metadata_language: Option<String>,    
+    pub preferred_metadata_country_code: Option<String>,    
     pub supports_sync: bool,    
     pub container: String,    
     pub sort_name: String,    
-    pub forced_sort_name: String,    
-    pub video3dformat: String,    
-    pub premiere_date: String,    
+    pub forced_sort_name: Option<String>,    
+    pub video3dformat: Option<String>,    
+    pub premiere_date: Option<String>,    
     pub external_urls: Vec<ExternalUrl>,    
     pub media_sources: Vec<MediaSource>,    
-    pub critic_rating: i64,    
-    pub production_locations: Vec<String>,    
+    pub critic_rating: Option<f64>,    
+    pub production_locations: Option<Vec<String>>,    
     pub path: String,    
     pub enable_media_source_display: bool,    
-    pub official_rating: String,    
-    pub custom_rating: String,    
+    pub official_rating: Option<String>,    
+    pub custom_rating: Option<String>,    
     pub channel_id: String,    
     pub channel_name: String,    
-    pub overview: String,    
-    pub taglines: Vec<String>,    
-    pub genres: Vec<String>,    
-    pub community_rating: i64,    
+    pub overview: Option<String>,    
+    pub taglines: Option<Vec<String>>,    
+    pub genres: Option<Vec<String>>,    
+    pub community_rating: Option<f64>,    
     pub cumulative_run_time_ticks: i64,    
-    pub run_time_ticks: i64,    
+    pub run_time_ticks: Option<i64>,    
     pub play_access: String,    
-    pub aspect_ratio: String,    
-    pub production_year: i64,    
+    pub aspect_ratio: Option<String>,    
+    pub production_year: Option<i64>,    
     pub is_place_holder: bool,    
     pub number: String,    
     pub channel_number: String,    
-    pub index_number: i64,    
-    pub index_number_end: i64,    
-    pub parent_index_number: i64,    
+    pub index_number: Option<i64>,    
+    pub index_number_end: Option<i64>,    
+    pub parent_index_number: Option<i64>,    
     pub remote_trailers: Vec<RemoteTrailer>,    
-    pub provider_ids: ProviderIds,    
+    pub provider_ids: Option<ProviderIds>,    
     pub is_hd: bool,    
     pub is_folder: bool,    
     pub parent_id: String,    
+    #[serde(rename = "Type")]
     pub type_field: String,    
-    pub people: Vec<People>,    
-    pub studios: Vec<Studio>,    
+    pub people: Option<Vec<People>>,    
+    pub studios: Option<Vec<Studio>>,    
     pub genre_items: Vec<GenreItem>,    
     pub parent_logo_item_id: String,    
     pub parent_backdrop_item_id: String,    
@@ -74,21 +85,21 @@ pub struct MediaItem {
     pub season_id: String,    
     pub special_feature_count: i64,    
     pub display_preferences_id: String,    
-    pub status: String,    
-    pub air_time: String,    
-    pub air_days: Vec<String>,    
-    pub tags: Vec<String>,    
-    pub primary_image_aspect_ratio: Option<i64>,    
+    pub status: Option<String>,    
+    pub air_time: Option<String>,    
+    pub air_days: Option<Vec<String>>,    
+    pub tags: Option<Vec<String>>,    
+    pub primary_image_aspect_ratio: Option<f64>,    
     pub artists: Vec<String>,    
-    pub artist_items: Vec<ArtistItem>,    
-    pub album: String,    
+    pub artist_items: Option<Vec<ArtistItem>>,    
+    pub album: Option<String>,    
     pub collection_type: String,    
-    pub display_order: String,    
+    pub display_order: Option<String>,    
     pub album_id: String,    
     pub album_primary_image_tag: String,    
     pub series_primary_image_tag: String,    
     pub album_artist: String,    
-    pub album_artists: Vec<AlbumArtist>,    
+    pub album_artists: Option<Vec<AlbumArtist>>,    
     pub season_name: String,    
     pub media_streams: Vec<MediaStream>,    
     pub video_type: String,    
@@ -107,12 +118,14 @@ pub struct MediaItem {
     pub parent_thumb_image_tag: String,    
     pub parent_primary_image_item_id: String,    
     pub parent_primary_image_tag: String,    
-    pub chapters: Vec<Chapter>,    
+    pub chapters: Vec<ChapterInfo>,    
+    /// Trickplay resolutions, keyed by media source id and then by width
+    pub trickplay: HashMap<String, HashMap<String, TrickplayInfo>>,
     pub location_type: String,    
     pub iso_type: String,    
     pub media_type: String,    
-    pub end_date: String,    
-    pub locked_fields: Vec<String>,    
+    pub end_date: Option<String>,    
+    pub locked_fields: Option<Vec<String>>,    
     pub trailer_count: i64,    
     pub movie_count: i64,    
     pub series_count: i64,    
@@ -123,8 +136,8 @@ pub struct MediaItem {
     pub artist_count: i64,    
     pub music_video_count: i64,    
     pub lock_data: bool,    
-    pub width: i64,    
-    pub height: i64,    
+    pub width: Option<i64>,    
+    pub height: Option<i64>,    
     pub camera_make: String,    
     pub camera_model: String,    
     pub software: String,    
@@ -162,20 +175,21 @@ pub struct MediaItem {
 pub struct CurrentProgram;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct ExternalUrl {
     pub name: String,    
     pub url: String
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct MediaSource {
     pub protocol: String,    
     pub id: String,    
     pub path: String,    
     pub encoder_path: String,    
     pub encoder_protocol: String,    
+    #[serde(rename = "Type")]
     pub type_field: String,    
     pub container: String,    
     pub size: i64,    
@@ -216,7 +230,7 @@ pub struct MediaSource {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct MediaStream {
     pub codec: String,    
     pub codec_tag: String,    
@@ -259,9 +273,10 @@ pub struct MediaStream {
     pub is_forced: bool,    
     pub height: i64,    
     pub width: i64,    
-    pub average_frame_rate: i64,    
-    pub real_frame_rate: i64,    
+    pub average_frame_rate: Option<f64>,    
+    pub real_frame_rate: Option<f64>,    
     pub profile: String,    
+    #[serde(rename = "Type")]
     pub type_field: String,    
     pub aspect_ratio: String,    
     pub index: i64,    
@@ -279,7 +294,7 @@ pub struct MediaStream {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct MediaAttachment {
     pub codec: String,    
     pub codec_tag: String,    
@@ -291,39 +306,36 @@ pub struct MediaAttachment {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct RequiredHttpHeaders {
     pub property1: String,
     pub property2: String
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct RemoteTrailer {
     pub url: String,    
     pub name: String
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-pub struct ProviderIds {
-    pub property1: String,
-    pub property2: String
-}
+/// The ids of an item in metadata providers, keyed by provider name, ie. "Tmdb" or "Imdb".
+pub type ProviderIds = HashMap<String, String>;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct People {
     pub name: String,    
     pub id: String,    
-    pub role: String,    
+    pub role: Option<String>,    
+    #[serde(rename = "Type")]
     pub type_field: String,    
     pub primary_image_tag: Option<String>,    
     pub image_blur_hashes: ImageBlurHashes
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct ImageBlurHashes {
     pub primary: Primary,    
     pub art: Art,    
@@ -340,149 +352,156 @@ pub struct ImageBlurHashes {
     pub profile: Profile,}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct Primary {
     pub property1: String,    
     pub property2: String
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct Art {
     pub property1: String,    
     pub property2: String
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct Backdrop {
     pub property1: String,    
     pub property2: String
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct Banner {
     pub property1: String,    
     pub property2: String
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct Logo {
     pub property1: String,    
     pub property2: String
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct Thumb {
     pub property1: String,    
     pub property2: String
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct Disc {
     pub property1: String,   
     pub property2: String
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct Box {
     pub property1: String,    
     pub property2: String
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct Screenshot {
     pub property1: String,   
     pub property2: String
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct Menu {
     pub property1: String,
     pub property2: String
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct Chapter {
     pub property1: String,    
     pub property2: String
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct BoxRear {
     pub property1: String,    
     pub property2: String
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct Profile {
     pub property1: String,    
     pub property2: String
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ChapterInfo {
+    pub start_position_ticks: i64,
+    pub name: Option<String>,
+    pub image_path: Option<String>,
+    pub image_date_modified: Option<String>,
+    /// The tag of the chapter's image, `None` if it has no image
+    pub image_tag: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
 pub struct Studio {
     pub name: String,    
     pub id: String
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct GenreItem {
     pub name: String,    
     pub id: String
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct UserData {
-    pub rating: i64,    
-    pub played_percentage: i64,    
-    pub unplayed_item_count: i64,    
+    pub rating: Option<f64>,    
+    pub played_percentage: Option<f64>,    
+    pub unplayed_item_count: Option<i64>,    
     pub playback_position_ticks: i64,    
     pub play_count: i64,    
     pub is_favorite: bool,    
-    pub likes: bool,    
-    pub last_played_date: String,    
+    pub likes: Option<bool>,    
+    pub last_played_date: Option<String>,    
     pub played: bool,    
     pub key: String,    
     pub item_id: String
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct ArtistItem {
     pub name: String,    
     pub id: String
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct AlbumArtist {
     pub name: String,    
     pub id: String
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-pub struct ImageTags {
-    pub property1: String,    
-    pub property2: String
-}
+/// The tags of an item's images, keyed by `ImageType` name, ie. "Primary" or "Logo".
+pub type ImageTags = HashMap<String, String>;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct FullNowPlayingItem {
     pub size: i64,    
     pub container: String,    
@@ -498,8 +517,382 @@ pub struct FullNowPlayingItem {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "PascalCase")]
+#[serde(rename_all = "PascalCase", default)]
 pub struct NowPlayingQueue {
     pub id: String,    
     pub playlist_item_id: Option<String>
-}
\ No newline at end of file
+}
+
+/// The fields to change with `update_user_item_data`, `None` fields are left untouched.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateUserItemData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rating: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub played_percentage: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unplayed_item_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub playback_position_ticks: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub play_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_favorite: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub likes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_played_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub played: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct MarkPlayedQuery {
+    date_played: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SetLikeQuery {
+    likes: bool,
+}
+
+impl JellyfinClient {
+    /// Gets an item with all of its fields, ie. to edit it with `update_item`.
+    /// List queries only return a subset of the fields.
+    pub async fn get_item(&self, user_id: &str, item_id: &str) -> Result<MediaItem> {
+        let req = self
+            .client
+            .get(format!("{}Users/{}/Items/{}", self.url, user_id, item_id))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Marks an item as played for a user.
+    /// * `date_played` When the item was played, defaults to now
+    pub async fn mark_played(
+        &self,
+        user_id: &str,
+        item_id: &str,
+        date_played: Option<String>,
+    ) -> Result<UserData> {
+        let req = self
+            .client
+            .post(format!(
+                "{}Users/{}/PlayedItems/{}",
+                self.url, user_id, item_id
+            ))
+            .query(&MarkPlayedQuery { date_played })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    pub async fn mark_unplayed(&self, user_id: &str, item_id: &str) -> Result<UserData> {
+        let req = self
+            .client
+            .delete(format!(
+                "{}Users/{}/PlayedItems/{}",
+                self.url, user_id, item_id
+            ))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    pub async fn mark_favorite(&self, user_id: &str, item_id: &str) -> Result<UserData> {
+        let req = self
+            .client
+            .post(format!(
+                "{}Users/{}/FavoriteItems/{}",
+                self.url, user_id, item_id
+            ))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    pub async fn unmark_favorite(&self, user_id: &str, item_id: &str) -> Result<UserData> {
+        let req = self
+            .client
+            .delete(format!(
+                "{}Users/{}/FavoriteItems/{}",
+                self.url, user_id, item_id
+            ))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Sets whether a user likes or dislikes an item.
+    pub async fn set_like(&self, user_id: &str, item_id: &str, likes: bool) -> Result<UserData> {
+        let req = self
+            .client
+            .post(format!(
+                "{}Users/{}/Items/{}/Rating",
+                self.url, user_id, item_id
+            ))
+            .query(&SetLikeQuery { likes })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    pub async fn clear_rating(&self, user_id: &str, item_id: &str) -> Result<UserData> {
+        let req = self
+            .client
+            .delete(format!(
+                "{}Users/{}/Items/{}/Rating",
+                self.url, user_id, item_id
+            ))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    pub async fn update_user_item_data(
+        &self,
+        user_id: &str,
+        item_id: &str,
+        new_data: UpdateUserItemData,
+    ) -> Result<UserData> {
+        let req = self
+            .client
+            .post(format!(
+                "{}Users/{}/Items/{}/UserData",
+                self.url, user_id, item_id
+            ))
+            .json(&new_data)
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct MediaItems {
+    pub items: Vec<MediaItem>,
+    pub total_record_count: u32,
+    pub start_index: u32,
+}
+
+/// Options for `get_next_up`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NextUpQuery {
+    pub user_id: Option<String>,
+    pub series_id: Option<String>,
+    pub parent_id: Option<String>,
+    pub start_index: Option<u32>,
+    pub limit: Option<u32>,
+    /// Episodes of series last watched before this date are excluded
+    pub next_up_date_cutoff: Option<String>,
+    /// Include the next episode of series that have already been watched
+    pub enable_rewatching: bool,
+    pub disable_first_episode: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetResumeItemsQuery {
+    parent_id: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", serialize_with = "crate::comma_separated")]
+    media_types: Vec<String>,
+    start_index: Option<u32>,
+    limit: Option<u32>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetLatestItemsQuery {
+    parent_id: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", serialize_with = "crate::comma_separated")]
+    include_item_types: Vec<String>,
+    is_played: Option<bool>,
+    limit: Option<u32>,
+    group_items: bool,
+}
+
+impl JellyfinClient {
+    /// Gets the items a user has started but not finished, ie. "Continue Watching".
+    /// * `media_types` Only include these media types, ie. "Video", empty for all
+    pub async fn get_resume_items(
+        &self,
+        user_id: &str,
+        parent_id: Option<String>,
+        media_types: Vec<String>,
+        start_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<MediaItems> {
+        let req = self
+            .client
+            .get(format!("{}Users/{}/Items/Resume", self.url, user_id))
+            .query(&GetResumeItemsQuery {
+                parent_id,
+                media_types,
+                start_index,
+                limit,
+            })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Gets the next episode to watch for each series in progress.
+    pub async fn get_next_up(&self, query: NextUpQuery) -> Result<MediaItems> {
+        let req = self
+            .client
+            .get(format!("{}Shows/NextUp", self.url))
+            .query(&query)
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Gets the latest items added to a library, following the user's home screen preferences.
+    /// Libraries in `latest_items_excludes` return no items, and played items are left out
+    /// if `hide_played_in_latest` is set.
+    /// * `parent_id` The library to get the latest items of, or all libraries if `None`
+    /// * `group_items` Group episodes of the same season together
+    pub async fn get_latest_items(
+        &self,
+        user: &User,
+        parent_id: Option<String>,
+        include_item_types: Vec<String>,
+        limit: Option<u32>,
+        group_items: bool,
+    ) -> Result<Vec<MediaItem>> {
+        if parent_id
+            .as_ref()
+            .is_some_and(|id| user.configuration.latest_items_excludes.contains(id))
+        {
+            return Ok(Vec::new());
+        }
+
+        let req = self
+            .client
+            .get(format!("{}Users/{}/Items/Latest", self.url, user.id))
+            .query(&GetLatestItemsQuery {
+                parent_id,
+                include_item_types,
+                is_played: user.configuration.hide_played_in_latest.then_some(false),
+                limit,
+                group_items,
+            })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serializes_missing_lists_as_null() {
+        let item: MediaItem =
+            serde_json::from_str(r#"{"Name":"Pilot","Id":"1","Type":"Episode","IndexNumber":1}"#)
+                .unwrap();
+        let value = serde_json::to_value(&item).unwrap();
+
+        assert_eq!(value["Name"], "Pilot");
+        assert_eq!(value["IndexNumber"], 1);
+        for key in ["People", "Studios", "ProviderIds", "Genres", "Tags", "Overview"] {
+            assert!(value[key].is_null(), "{} should be null", key);
+        }
+    }
+}
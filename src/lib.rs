@@ -1,3 +1,4 @@
+use serde::Serializer;
 use url::Url;
 use user::UserAuth;
 
@@ -6,6 +7,25 @@ pub mod user;
 pub mod err;
 pub mod session;
 pub mod items;
+pub mod tv;
+pub mod library;
+pub mod search;
+pub mod browse;
+pub mod recommendations;
+pub mod playlists;
+pub mod collections;
+pub mod metadata;
+pub mod images;
+pub mod subtitles;
+pub mod lyrics;
+pub mod trickplay;
+pub mod system;
+pub mod tasks;
+pub mod plugins;
+pub mod packages;
+pub mod configuration;
+#[cfg(feature = "websocket")]
+pub mod socket;
 
 #[derive(Debug, Clone)]
 pub struct JellyfinClient {
@@ -52,4 +72,9 @@ impl JellyfinClient {
         client.auth_user_name(username.into(), password.into()).await?;
         Ok(client)
     }
-}
\ No newline at end of file
+}
+
+/// Serializes a list as the comma delimited string the jellyfin API expects in queries.
+pub(crate) fn comma_separated<S: Serializer>(value: &[String], serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_str(&value.join(","))
+}
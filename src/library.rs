@@ -0,0 +1,574 @@
+use std::collections::HashMap;
+
+use super::err::Result;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use serde_json::json;
+use serde_json::Value;
+
+use crate::err::JellyfinError;
+use crate::JellyfinClient;
+
+/// The kind of content a library holds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CollectionType {
+    Movies,
+    TvShows,
+    Music,
+    MusicVideos,
+    Trailers,
+    HomeVideos,
+    BoxSets,
+    Books,
+    Photos,
+    LiveTv,
+    Playlists,
+    Folders,
+    Mixed,
+    #[serde(other)]
+    Unknown,
+}
+
+/// The kinds of library that can be created with `add_virtual_folder`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CollectionTypeOptions {
+    Movies,
+    TvShows,
+    Music,
+    MusicVideos,
+    HomeVideos,
+    BoxSets,
+    Books,
+    Mixed,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct Library {
+    pub name: String,
+    pub server_id: String,
+    pub id: String,
+    pub etag: Option<String>,
+    pub date_created: Option<String>,
+    pub sort_name: Option<String>,
+    pub path: Option<String>,
+    pub is_folder: bool,
+    pub parent_id: Option<String>,
+    #[serde(rename = "Type")]
+    pub type_field: String,
+    pub collection_type: Option<CollectionType>,
+    pub image_tags: HashMap<String, String>,
+    pub backdrop_image_tags: Vec<String>,
+    pub location_type: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct Libraries {
+    pub items: Vec<Library>,
+    pub total_record_count: u32,
+    pub start_index: u32,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct VirtualFolderInfo {
+    pub name: String,
+    pub locations: Vec<String>,
+    pub collection_type: Option<CollectionType>,
+    pub library_options: Option<LibraryOptions>,
+    pub item_id: String,
+    pub primary_image_item_id: Option<String>,
+    pub refresh_progress: Option<f64>,
+    pub refresh_status: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct LibraryOptions {
+    pub enable_photos: bool,
+    pub enable_realtime_monitor: bool,
+    pub enable_chapter_image_extraction: bool,
+    pub extract_chapter_images_during_library_scan: bool,
+    pub path_infos: Vec<MediaPathInfo>,
+    pub save_local_metadata: bool,
+    pub enable_internet_providers: bool,
+    pub enable_automatic_series_grouping: bool,
+    pub enable_embedded_titles: bool,
+    pub enable_embedded_episode_infos: bool,
+    pub automatic_refresh_interval_days: i64,
+    pub preferred_metadata_language: Option<String>,
+    pub metadata_country_code: Option<String>,
+    pub season_zero_display_name: String,
+    pub metadata_savers: Option<Vec<String>>,
+    pub disabled_local_metadata_readers: Vec<String>,
+    pub local_metadata_reader_order: Option<Vec<String>>,
+    pub disabled_subtitle_fetchers: Vec<String>,
+    pub subtitle_fetcher_order: Vec<String>,
+    pub skip_subtitles_if_embedded_subtitles_present: bool,
+    pub skip_subtitles_if_audio_track_matches: bool,
+    pub subtitle_download_languages: Option<Vec<String>>,
+    pub require_perfect_subtitle_match: bool,
+    pub save_subtitles_with_media: bool,
+    pub automatically_add_to_collection: bool,
+    pub type_options: Vec<TypeOptions>,
+    /// Options not covered above, kept so updates don't reset them
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// The metadata and image fetchers used for one item type, ie. "Movie".
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct TypeOptions {
+    #[serde(rename = "Type")]
+    pub type_field: String,
+    pub metadata_fetchers: Vec<String>,
+    pub metadata_fetcher_order: Vec<String>,
+    pub image_fetchers: Vec<String>,
+    pub image_fetcher_order: Vec<String>,
+    pub image_options: Vec<ImageOption>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ImageOption {
+    #[serde(rename = "Type")]
+    pub type_field: String,
+    pub limit: i64,
+    pub min_width: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct MediaPathInfo {
+    pub path: String,
+    pub network_path: Option<String>,
+}
+
+/// How much of an item's metadata or images to refresh.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MetadataRefreshMode {
+    None,
+    ValidationOnly,
+    #[default]
+    Default,
+    FullRefresh,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RefreshOptions {
+    pub metadata_refresh_mode: MetadataRefreshMode,
+    pub image_refresh_mode: MetadataRefreshMode,
+    /// Replace all existing metadata instead of only filling in missing fields
+    pub replace_all_metadata: bool,
+    /// Replace all existing images instead of only filling in missing ones
+    pub replace_all_images: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MediaUpdateType {
+    Created,
+    Modified,
+    Deleted,
+}
+
+/// A change to a file or folder on disk, reported with `report_media_updates`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MediaUpdate {
+    pub path: String,
+    pub update_type: MediaUpdateType,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct AddVirtualFolderQuery {
+    name: String,
+    collection_type: Option<CollectionTypeOptions>,
+    refresh_library: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RemoveVirtualFolderQuery {
+    name: String,
+    refresh_library: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RenameVirtualFolderQuery {
+    name: String,
+    new_name: String,
+    refresh_library: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RefreshLibraryQuery {
+    refresh_library: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RemoveMediaPathQuery {
+    name: String,
+    path: String,
+    refresh_library: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetUserViewsQuery {
+    include_hidden: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetMediaFoldersQuery {
+    is_hidden: Option<bool>,
+}
+
+impl JellyfinClient {
+    /// Gets the libraries a user can see, in the user's `ordered_views` order.
+    /// * `include_hidden` Include the libraries in the user's `my_media_excludes`
+    pub async fn get_user_views(&self, user_id: &str, include_hidden: bool) -> Result<Libraries> {
+        let req = self
+            .client
+            .get(format!("{}Users/{}/Views", self.url, user_id))
+            .query(&GetUserViewsQuery { include_hidden })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Gets all the top level media folders on the server.
+    pub async fn get_media_folders(&self, is_hidden: Option<bool>) -> Result<Libraries> {
+        let req = self
+            .client
+            .get(format!("{}Library/MediaFolders", self.url))
+            .query(&GetMediaFoldersQuery { is_hidden })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Gets the libraries configured on the server, along with their paths.
+    pub async fn get_virtual_folders(&self) -> Result<Vec<VirtualFolderInfo>> {
+        let req = self
+            .client
+            .get(format!("{}Library/VirtualFolders", self.url))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Creates a new library.
+    /// * `name` The name of the library
+    /// * `collection_type` The kind of library, or mixed content if `None`
+    /// * `paths` The folders the library is made of
+    /// * `refresh_library` Scan the library once it is created
+    pub async fn add_virtual_folder(
+        &self,
+        name: &str,
+        collection_type: Option<CollectionTypeOptions>,
+        paths: Vec<String>,
+        library_options: LibraryOptions,
+        refresh_library: bool,
+    ) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Library/VirtualFolders", self.url))
+            .query(&AddVirtualFolderQuery {
+                name: name.to_string(),
+                collection_type,
+                refresh_library,
+            })
+            .query(&paths.iter().map(|path| ("paths", path)).collect::<Vec<_>>())
+            .json(&json!({ "LibraryOptions": library_options }))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
+    pub async fn remove_virtual_folder(&self, name: &str, refresh_library: bool) -> Result<()> {
+        let _req = self
+            .client
+            .delete(format!("{}Library/VirtualFolders", self.url))
+            .query(&RemoveVirtualFolderQuery {
+                name: name.to_string(),
+                refresh_library,
+            })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
+    pub async fn rename_virtual_folder(
+        &self,
+        name: &str,
+        new_name: &str,
+        refresh_library: bool,
+    ) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Library/VirtualFolders/Name", self.url))
+            .query(&RenameVirtualFolderQuery {
+                name: name.to_string(),
+                new_name: new_name.to_string(),
+                refresh_library,
+            })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
+    /// Adds a folder to a library.
+    /// * `name` The name of the library
+    pub async fn add_media_path(
+        &self,
+        name: &str,
+        path_info: MediaPathInfo,
+        refresh_library: bool,
+    ) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Library/VirtualFolders/Paths", self.url))
+            .query(&RefreshLibraryQuery { refresh_library })
+            .json(&json!({
+                "Name": name,
+                "PathInfo": path_info
+            }))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
+    /// Updates the network path of a folder in a library, the folder is matched by `path_info.path`.
+    pub async fn update_media_path(&self, name: &str, path_info: MediaPathInfo) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Library/VirtualFolders/Paths/Update", self.url))
+            .json(&json!({
+                "Name": name,
+                "PathInfo": path_info
+            }))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
+    pub async fn remove_media_path(
+        &self,
+        name: &str,
+        path: &str,
+        refresh_library: bool,
+    ) -> Result<()> {
+        let _req = self
+            .client
+            .delete(format!("{}Library/VirtualFolders/Paths", self.url))
+            .query(&RemoveMediaPathQuery {
+                name: name.to_string(),
+                path: path.to_string(),
+                refresh_library,
+            })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
+    /// Gets the options of a library, returns `None` if there is no library with the id.
+    /// * `id` The `item_id` of the library
+    pub async fn get_library_options(&self, id: &str) -> Result<Option<LibraryOptions>> {
+        Ok(self
+            .get_virtual_folders()
+            .await?
+            .into_iter()
+            .find(|folder| folder.item_id == id)
+            .and_then(|folder| folder.library_options))
+    }
+
+    /// Replaces the options of a library.
+    /// * `id` The `item_id` of the library
+    pub async fn update_library_options(&self, id: &str, library_options: LibraryOptions) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Library/VirtualFolders/LibraryOptions", self.url))
+            .json(&json!({
+                "Id": id,
+                "LibraryOptions": library_options
+            }))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
+    /// Starts a scan of all libraries.
+    pub async fn refresh_library(&self) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Library/Refresh", self.url))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Queues a refresh of the metadata and images of an item.
+    pub async fn refresh_item(&self, id: &str, options: RefreshOptions) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Items/{}/Refresh", self.url, id))
+            .query(&options)
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Tells the server which files changed, so only those are rescanned.
+    pub async fn report_media_updates(&self, updates: Vec<MediaUpdate>) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Library/Media/Updated", self.url))
+            .json(&json!({ "Updates": updates }))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn report_media_added(&self, paths: Vec<String>) -> Result<()> {
+        self.report_media_updates(media_updates(paths, MediaUpdateType::Created))
+            .await
+    }
+
+    pub async fn report_media_modified(&self, paths: Vec<String>) -> Result<()> {
+        self.report_media_updates(media_updates(paths, MediaUpdateType::Modified))
+            .await
+    }
+
+    pub async fn report_media_removed(&self, paths: Vec<String>) -> Result<()> {
+        self.report_media_updates(media_updates(paths, MediaUpdateType::Deleted))
+            .await
+    }
+}
+
+fn media_updates(paths: Vec<String>, update_type: MediaUpdateType) -> Vec<MediaUpdate> {
+    paths
+        .into_iter()
+        .map(|path| MediaUpdate { path, update_type })
+        .collect()
+}
@@ -0,0 +1,180 @@
+use super::err::Result;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+use crate::err::JellyfinError;
+use crate::JellyfinClient;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct Lyrics {
+    pub metadata: LyricMetadata,
+    pub lyrics: Vec<LyricLine>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct LyricMetadata {
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub title: Option<String>,
+    pub author: Option<String>,
+    /// The length of the song in ticks
+    pub length: Option<i64>,
+    pub by: Option<String>,
+    /// The offset applied to every line in ticks
+    pub offset: Option<i64>,
+    pub creator: Option<String>,
+    pub version: Option<String>,
+    pub is_synced: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct LyricLine {
+    pub text: String,
+    /// When the line starts in ticks, `None` for unsynced lyrics
+    pub start: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct RemoteLyricInfo {
+    pub id: String,
+    pub provider_name: String,
+    pub lyrics: Lyrics,
+}
+
+impl Lyrics {
+    /// Gets the line being sung at a position, for synced lyrics.
+    pub fn line_at(&self, position_ticks: i64) -> Option<&LyricLine> {
+        self.lyrics
+            .iter()
+            .take_while(|line| line.start.is_some_and(|start| start <= position_ticks))
+            .last()
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct UploadLyricsQuery {
+    file_name: String,
+}
+
+impl JellyfinClient {
+    pub async fn get_lyrics(&self, item_id: &str) -> Result<Lyrics> {
+        let req = self
+            .client
+            .get(format!("{}Audio/{}/Lyrics", self.url, item_id))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Uploads a lyrics file for an audio item.
+    /// * `file_name` The name of the file, its extension decides the format, ie. "song.lrc"
+    /// * `data` The contents of the lyrics file
+    pub async fn upload_lyrics(&self, item_id: &str, file_name: &str, data: String) -> Result<Lyrics> {
+        let req = self
+            .client
+            .post(format!("{}Audio/{}/Lyrics", self.url, item_id))
+            .query(&UploadLyricsQuery {
+                file_name: file_name.to_string(),
+            })
+            .header("Content-Type", "text/plain")
+            .body(data)
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    pub async fn delete_lyrics(&self, item_id: &str) -> Result<()> {
+        let _req = self
+            .client
+            .delete(format!("{}Audio/{}/Lyrics", self.url, item_id))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Searches lyric providers for lyrics of an audio item.
+    pub async fn search_remote_lyrics(&self, item_id: &str) -> Result<Vec<RemoteLyricInfo>> {
+        let req = self
+            .client
+            .get(format!("{}Audio/{}/RemoteSearch/Lyrics", self.url, item_id))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Downloads lyrics found with `search_remote_lyrics` and saves them for the item.
+    /// * `lyric_id` The `id` of the `RemoteLyricInfo`
+    pub async fn download_remote_lyrics(&self, item_id: &str, lyric_id: &str) -> Result<Lyrics> {
+        let req = self
+            .client
+            .post(format!(
+                "{}Audio/{}/RemoteSearch/Lyrics/{}",
+                self.url, item_id, lyric_id
+            ))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn finds_line_at_position() {
+        let lyrics: Lyrics = serde_json::from_str(
+            r#"{"Metadata":{"IsSynced":true},"Lyrics":[{"Text":"one","Start":0},{"Text":"two","Start":100},{"Text":"three","Start":200}]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(lyrics.line_at(150).map(|line| line.text.as_str()), Some("two"));
+        assert_eq!(lyrics.line_at(200).map(|line| line.text.as_str()), Some("three"));
+        assert_eq!(lyrics.line_at(-1), None);
+    }
+}
@@ -0,0 +1,243 @@
+use super::err::Result;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use serde_json::json;
+
+use crate::err::JellyfinError;
+use crate::items::MediaItem;
+use crate::items::ProviderIds;
+use crate::JellyfinClient;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct MetadataEditorInfo {
+    pub parental_rating_options: Vec<ParentalRating>,
+    pub countries: Vec<CountryInfo>,
+    pub cultures: Vec<CultureInfo>,
+    pub external_id_infos: Vec<ExternalIdInfo>,
+    pub content_type: Option<String>,
+    pub content_type_options: Vec<NameValuePair>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ParentalRating {
+    pub name: String,
+    pub value: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct CountryInfo {
+    pub name: String,
+    pub display_name: String,
+    #[serde(rename = "TwoLetterISORegionName")]
+    pub two_letter_iso_region_name: String,
+    #[serde(rename = "ThreeLetterISORegionName")]
+    pub three_letter_iso_region_name: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct CultureInfo {
+    pub name: String,
+    pub display_name: String,
+    #[serde(rename = "TwoLetterISOLanguageName")]
+    pub two_letter_iso_language_name: String,
+    #[serde(rename = "ThreeLetterISOLanguageName")]
+    pub three_letter_iso_language_name: Option<String>,
+    #[serde(rename = "ThreeLetterISOLanguageNames")]
+    pub three_letter_iso_language_names: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ExternalIdInfo {
+    pub name: String,
+    /// The key of the id in `ProviderIds`
+    pub key: String,
+    #[serde(rename = "Type")]
+    pub type_field: Option<String>,
+    pub url_format_string: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct NameValuePair {
+    pub name: String,
+    pub value: String,
+}
+
+/// The kind of item to search remote metadata providers for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RemoteSearchType {
+    Movie,
+    Series,
+    Trailer,
+    MusicAlbum,
+    MusicArtist,
+    Person,
+    MusicVideo,
+    Book,
+    BoxSet,
+}
+
+impl RemoteSearchType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Movie => "Movie",
+            Self::Series => "Series",
+            Self::Trailer => "Trailer",
+            Self::MusicAlbum => "MusicAlbum",
+            Self::MusicArtist => "MusicArtist",
+            Self::Person => "Person",
+            Self::MusicVideo => "MusicVideo",
+            Self::Book => "Book",
+            Self::BoxSet => "BoxSet",
+        }
+    }
+}
+
+/// What to look for in a remote search, fields left as `None` are not matched on.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RemoteSearchInfo {
+    pub name: Option<String>,
+    pub original_title: Option<String>,
+    pub year: Option<i64>,
+    pub provider_ids: ProviderIds,
+    pub metadata_language: Option<String>,
+    pub metadata_country_code: Option<String>,
+    pub index_number: Option<i64>,
+    pub parent_index_number: Option<i64>,
+    pub premiere_date: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct RemoteSearchResult {
+    pub name: String,
+    pub provider_ids: ProviderIds,
+    pub production_year: Option<i64>,
+    pub index_number: Option<i64>,
+    pub index_number_end: Option<i64>,
+    pub parent_index_number: Option<i64>,
+    pub premiere_date: Option<String>,
+    pub image_url: Option<String>,
+    pub search_provider_name: Option<String>,
+    pub overview: Option<String>,
+    pub album_artist: Option<Box<RemoteSearchResult>>,
+    pub artists: Vec<RemoteSearchResult>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ApplyRemoteSearchResultQuery {
+    replace_all_images: bool,
+}
+
+impl JellyfinClient {
+    /// Gets the options available when editing an item's metadata, ie. ratings, countries and external ids.
+    pub async fn get_metadata_editor_info(&self, item_id: &str) -> Result<MetadataEditorInfo> {
+        let req = self
+            .client
+            .get(format!("{}Items/{}/MetadataEditor", self.url, item_id))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Saves the edited metadata of an item.
+    /// The item's `locked_fields` and `lock_data` are saved with it, so that the edited
+    /// fields are not overwritten by the next metadata refresh.
+    /// The server overwrites every editable field with the posted value, so `item` should be
+    /// fetched with `get_item` and edited in place, fields left as `None` are cleared.
+    /// Items returned by list queries are missing most fields and must not be posted back.
+    pub async fn update_item(&self, id: &str, item: MediaItem) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Items/{}", self.url, id))
+            .json(&item)
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
+    /// Searches metadata providers for candidates matching `search_info`.
+    /// * `item_id` The item being identified, used to pick the providers enabled for its library
+    /// * `search_provider_name` Only search this provider, ie. "TheMovieDb"
+    pub async fn remote_search(
+        &self,
+        search_type: RemoteSearchType,
+        search_info: RemoteSearchInfo,
+        item_id: Option<String>,
+        search_provider_name: Option<String>,
+    ) -> Result<Vec<RemoteSearchResult>> {
+        let req = self
+            .client
+            .post(format!(
+                "{}Items/RemoteSearch/{}",
+                self.url,
+                search_type.as_str()
+            ))
+            .json(&json!({
+                "SearchInfo": search_info,
+                "ItemId": item_id,
+                "SearchProviderName": search_provider_name,
+                "IncludeDisabledProviders": false
+            }))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Re-identifies an item as a result returned by `remote_search`, and refreshes its metadata.
+    pub async fn apply_remote_search_result(
+        &self,
+        item_id: &str,
+        result: RemoteSearchResult,
+        replace_all_images: bool,
+    ) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Items/RemoteSearch/Apply/{}", self.url, item_id))
+            .query(&ApplyRemoteSearchResultQuery { replace_all_images })
+            .json(&result)
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+}
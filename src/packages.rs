@@ -0,0 +1,191 @@
+use super::err::Result;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use url::Url;
+
+use crate::err::JellyfinError;
+use crate::JellyfinClient;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PackageInfo {
+    pub name: String,
+    pub description: String,
+    pub overview: String,
+    pub owner: String,
+    pub category: String,
+    /// The id of the plugin the package installs
+    pub guid: String,
+    pub versions: Vec<PackageVersionInfo>,
+    pub image_url: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PackageVersionInfo {
+    pub version: String,
+    pub changelog: Option<String>,
+    pub target_abi: Option<String>,
+    pub source_url: Option<String>,
+    pub checksum: Option<String>,
+    pub timestamp: Option<String>,
+    pub repository_name: String,
+    pub repository_url: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct RepositoryInfo {
+    pub name: String,
+    pub url: String,
+    pub enabled: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetPackageQuery {
+    assembly_guid: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct InstallPackageQuery {
+    assembly_guid: Option<String>,
+    version: Option<String>,
+    repository_url: Option<String>,
+}
+
+impl JellyfinClient {
+    /// Gets the packages available from all enabled repositories.
+    pub async fn get_packages(&self) -> Result<Vec<PackageInfo>> {
+        let req = self
+            .client
+            .get(format!("{}Packages", self.url))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Gets a package by name.
+    /// * `assembly_guid` The `PackageInfo::guid`, to tell packages with the same name apart
+    pub async fn get_package(&self, name: &str, assembly_guid: Option<String>) -> Result<PackageInfo> {
+        let req = self
+            .client
+            .get(self.package_url("Packages", name)?)
+            .query(&GetPackageQuery { assembly_guid })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Installs a package, takes effect once the server restarts.
+    /// * `version` The version to install, or the latest if `None`
+    /// * `repository_url` The repository to install from, or any enabled repository if `None`
+    pub async fn install_package(
+        &self,
+        name: &str,
+        assembly_guid: Option<String>,
+        version: Option<String>,
+        repository_url: Option<String>,
+    ) -> Result<()> {
+        let _req = self
+            .client
+            .post(self.package_url("Packages/Installed", name)?)
+            .query(&InstallPackageQuery {
+                assembly_guid,
+                version,
+                repository_url,
+            })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Cancels a package installation that is still in progress.
+    /// * `package_id` The `PackageInfo::guid` of the package
+    pub async fn cancel_package_installation(&self, package_id: &str) -> Result<()> {
+        let _req = self
+            .client
+            .delete(format!("{}Packages/Installing/{}", self.url, package_id))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_repositories(&self) -> Result<Vec<RepositoryInfo>> {
+        let req = self
+            .client
+            .get(format!("{}Repositories", self.url))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Replaces the list of plugin repositories.
+    pub async fn set_repositories(&self, repositories: Vec<RepositoryInfo>) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Repositories", self.url))
+            .json(&repositories)
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Package names can contain spaces and other reserved characters, so they are pushed as an encoded segment.
+    fn package_url(&self, path: &str, name: &str) -> Result<Url> {
+        let mut url = Url::parse(&format!("{}{}", self.url, path))?;
+        url.path_segments_mut()
+            .map_err(|_| url::ParseError::RelativeUrlWithCannotBeABaseBase)?
+            .push(name);
+        Ok(url)
+    }
+}
@@ -0,0 +1,243 @@
+use super::err::Result;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use serde_json::json;
+
+use crate::err::JellyfinError;
+use crate::items::MediaItems;
+use crate::JellyfinClient;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct PlaylistUserPermissions {
+    pub user_id: String,
+    pub can_edit: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NewPlaylist {
+    pub name: String,
+    /// The items to add to the playlist
+    pub ids: Vec<String>,
+    /// The owner of the playlist
+    pub user_id: Option<String>,
+    /// ie. "Audio" or "Video"
+    pub media_type: Option<String>,
+    /// Other users that can access the playlist
+    pub users: Vec<PlaylistUserPermissions>,
+    pub is_public: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct PlaylistCreationResult {
+    pub id: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct AddToPlaylistQuery {
+    #[serde(serialize_with = "crate::comma_separated")]
+    ids: Vec<String>,
+    user_id: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RemoveFromPlaylistQuery {
+    #[serde(serialize_with = "crate::comma_separated")]
+    entry_ids: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetPlaylistItemsQuery {
+    user_id: Option<String>,
+    start_index: Option<u32>,
+    limit: Option<u32>,
+}
+
+impl JellyfinClient {
+    pub async fn create_playlist(&self, playlist: NewPlaylist) -> Result<PlaylistCreationResult> {
+        let req = self
+            .client
+            .post(format!("{}Playlists", self.url))
+            .json(&playlist)
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    pub async fn add_to_playlist(
+        &self,
+        playlist_id: &str,
+        ids: Vec<String>,
+        user_id: Option<String>,
+    ) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Playlists/{}/Items", self.url, playlist_id))
+            .query(&AddToPlaylistQuery { ids, user_id })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Removes entries from a playlist.
+    /// * `entry_ids` The `playlist_item_id`s of the entries, not the item ids
+    pub async fn remove_from_playlist(&self, playlist_id: &str, entry_ids: Vec<String>) -> Result<()> {
+        let _req = self
+            .client
+            .delete(format!("{}Playlists/{}/Items", self.url, playlist_id))
+            .query(&RemoveFromPlaylistQuery { entry_ids })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Moves an entry of a playlist to a new position.
+    /// * `playlist_item_id` The `MediaItem::playlist_item_id` of the entry, as returned by `get_playlist_items`
+    pub async fn move_playlist_item(
+        &self,
+        playlist_id: &str,
+        playlist_item_id: &str,
+        new_index: u32,
+    ) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!(
+                "{}Playlists/{}/Items/{}/Move/{}",
+                self.url, playlist_id, playlist_item_id, new_index
+            ))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_playlist_items(
+        &self,
+        playlist_id: &str,
+        user_id: Option<String>,
+        start_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<MediaItems> {
+        let req = self
+            .client
+            .get(format!("{}Playlists/{}/Items", self.url, playlist_id))
+            .query(&GetPlaylistItemsQuery {
+                user_id,
+                start_index,
+                limit,
+            })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Gets the users a playlist is shared with.
+    pub async fn get_playlist_users(&self, playlist_id: &str) -> Result<Vec<PlaylistUserPermissions>> {
+        let req = self
+            .client
+            .get(format!("{}Playlists/{}/Users", self.url, playlist_id))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Shares a playlist with a user, or changes whether they can edit it.
+    pub async fn update_playlist_user(
+        &self,
+        playlist_id: &str,
+        user_id: &str,
+        can_edit: bool,
+    ) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!(
+                "{}Playlists/{}/Users/{}",
+                self.url, playlist_id, user_id
+            ))
+            .json(&json!({ "CanEdit": can_edit }))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Stops sharing a playlist with a user.
+    pub async fn remove_playlist_user(&self, playlist_id: &str, user_id: &str) -> Result<()> {
+        let _req = self
+            .client
+            .delete(format!(
+                "{}Playlists/{}/Users/{}",
+                self.url, playlist_id, user_id
+            ))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+}
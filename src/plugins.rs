@@ -0,0 +1,173 @@
+use super::err::Result;
+use serde::de::DeserializeOwned;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+use crate::err::JellyfinError;
+use crate::JellyfinClient;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct PluginInfo {
+    pub name: String,
+    pub version: String,
+    pub configuration_file_name: Option<String>,
+    pub description: String,
+    pub id: String,
+    pub can_uninstall: bool,
+    pub has_image: bool,
+    /// ie. "Active", "Disabled", "Restart" or "Malfunctioned"
+    pub status: String,
+}
+
+/// The `meta.json` of an installed plugin.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PluginManifest {
+    pub category: String,
+    pub changelog: String,
+    pub description: String,
+    pub guid: String,
+    pub name: String,
+    pub overview: String,
+    pub owner: String,
+    pub target_abi: String,
+    pub timestamp: String,
+    pub version: String,
+    pub status: String,
+    pub auto_update: bool,
+    pub image_path: Option<String>,
+    pub assemblies: Vec<String>,
+}
+
+impl JellyfinClient {
+    pub async fn get_plugins(&self) -> Result<Vec<PluginInfo>> {
+        let req = self
+            .client
+            .get(format!("{}Plugins", self.url))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Enables a plugin, takes effect once the server restarts.
+    pub async fn enable_plugin(&self, plugin_id: &str, version: &str) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Plugins/{}/{}/Enable", self.url, plugin_id, version))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Disables a plugin, takes effect once the server restarts.
+    pub async fn disable_plugin(&self, plugin_id: &str, version: &str) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Plugins/{}/{}/Disable", self.url, plugin_id, version))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Uninstalls a plugin, takes effect once the server restarts.
+    pub async fn uninstall_plugin(&self, plugin_id: &str, version: &str) -> Result<()> {
+        let _req = self
+            .client
+            .delete(format!("{}Plugins/{}/{}", self.url, plugin_id, version))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Gets the configuration of a plugin.
+    /// Use `serde_json::Value` as `T` to get it as arbitrary json.
+    pub async fn get_plugin_configuration<T: DeserializeOwned>(&self, plugin_id: &str) -> Result<T> {
+        let req = self
+            .client
+            .get(format!("{}Plugins/{}/Configuration", self.url, plugin_id))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Replaces the configuration of a plugin.
+    pub async fn update_plugin_configuration<T: serde::Serialize>(
+        &self,
+        plugin_id: &str,
+        configuration: T,
+    ) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Plugins/{}/Configuration", self.url, plugin_id))
+            .json(&configuration)
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_plugin_manifest(&self, plugin_id: &str) -> Result<PluginManifest> {
+        let req = self
+            .client
+            .post(format!("{}Plugins/{}/Manifest", self.url, plugin_id))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+}
@@ -0,0 +1,141 @@
+use super::err::Result;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use url::Url;
+
+use crate::err::JellyfinError;
+use crate::items::MediaItem;
+use crate::items::MediaItems;
+use crate::JellyfinClient;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct Recommendation {
+    pub items: Vec<MediaItem>,
+    /// ie. "SimilarToRecentlyPlayed" or "HasDirectorFromRecentlyPlayed"
+    pub recommendation_type: String,
+    pub baseline_item_name: Option<String>,
+    pub category_id: String,
+}
+
+/// What an instant mix is generated from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstantMixSource {
+    Item(String),
+    Album(String),
+    Artist(String),
+    Playlist(String),
+    /// The name of the music genre
+    MusicGenre(String),
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetSimilarItemsQuery {
+    user_id: Option<String>,
+    limit: Option<u32>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetMovieRecommendationsQuery {
+    user_id: Option<String>,
+    parent_id: Option<String>,
+    category_limit: Option<u32>,
+    item_limit: Option<u32>,
+}
+
+impl JellyfinClient {
+    /// Gets items similar to an item, ie. "More Like This".
+    pub async fn get_similar_items(
+        &self,
+        item_id: &str,
+        user_id: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<MediaItems> {
+        let req = self
+            .client
+            .get(format!("{}Items/{}/Similar", self.url, item_id))
+            .query(&GetSimilarItemsQuery { user_id, limit })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Gets movie recommendations, grouped by why they were recommended.
+    /// * `category_limit` The max number of categories
+    /// * `item_limit` The max number of items per category
+    pub async fn get_movie_recommendations(
+        &self,
+        user_id: Option<String>,
+        parent_id: Option<String>,
+        category_limit: Option<u32>,
+        item_limit: Option<u32>,
+    ) -> Result<Vec<Recommendation>> {
+        let req = self
+            .client
+            .get(format!("{}Movies/Recommendations", self.url))
+            .query(&GetMovieRecommendationsQuery {
+                user_id,
+                parent_id,
+                category_limit,
+                item_limit,
+            })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Creates a playlist of songs similar to the source.
+    pub async fn get_instant_mix(
+        &self,
+        source: InstantMixSource,
+        user_id: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<MediaItems> {
+        let (path, id) = match source {
+            InstantMixSource::Item(id) => ("Items", id),
+            InstantMixSource::Album(id) => ("Albums", id),
+            InstantMixSource::Artist(id) => ("Artists", id),
+            InstantMixSource::Playlist(id) => ("Playlists", id),
+            InstantMixSource::MusicGenre(name) => ("MusicGenres", name),
+        };
+        let mut url = Url::parse(&format!("{}{}", self.url, path))?;
+        url.path_segments_mut()
+            .map_err(|_| url::ParseError::RelativeUrlWithCannotBeABaseBase)?
+            .push(&id)
+            .push("InstantMix");
+
+        let req = self
+            .client
+            .get(url)
+            .query(&GetSimilarItemsQuery { user_id, limit })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+}
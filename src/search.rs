@@ -0,0 +1,105 @@
+use super::err::Result;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+use crate::err::JellyfinError;
+use crate::JellyfinClient;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct SearchHint {
+    pub item_id: String,
+    pub id: String,
+    pub name: String,
+    pub matched_term: Option<String>,
+    pub index_number: Option<i64>,
+    pub production_year: Option<i64>,
+    pub parent_index_number: Option<i64>,
+    pub primary_image_tag: Option<String>,
+    pub thumb_image_tag: Option<String>,
+    pub thumb_image_item_id: Option<String>,
+    pub backdrop_image_tag: Option<String>,
+    pub backdrop_image_item_id: Option<String>,
+    #[serde(rename = "Type")]
+    pub type_field: String,
+    pub is_folder: Option<bool>,
+    pub run_time_ticks: Option<i64>,
+    pub media_type: Option<String>,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub series: Option<String>,
+    pub status: Option<String>,
+    pub album: Option<String>,
+    pub album_id: Option<String>,
+    pub album_artist: Option<String>,
+    pub artists: Vec<String>,
+    pub song_count: Option<i64>,
+    pub episode_count: Option<i64>,
+    pub channel_id: Option<String>,
+    pub channel_name: Option<String>,
+    pub primary_image_aspect_ratio: Option<f64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct SearchHints {
+    pub search_hints: Vec<SearchHint>,
+    pub total_record_count: u32,
+}
+
+/// Options for `search_hints`. Empty lists don't filter anything.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SearchQuery {
+    pub search_term: String,
+    pub user_id: Option<String>,
+    pub start_index: Option<u32>,
+    pub limit: Option<u32>,
+    /// Only include these item types, ie. "Movie" or "Series"
+    #[serde(skip_serializing_if = "Vec::is_empty", serialize_with = "crate::comma_separated")]
+    pub include_item_types: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", serialize_with = "crate::comma_separated")]
+    pub exclude_item_types: Vec<String>,
+    /// Only include these media types, ie. "Video" or "Audio"
+    #[serde(skip_serializing_if = "Vec::is_empty", serialize_with = "crate::comma_separated")]
+    pub media_types: Vec<String>,
+    pub parent_id: Option<String>,
+}
+
+impl SearchQuery {
+    pub fn new<T: Into<String>>(search_term: T) -> Self {
+        Self {
+            search_term: search_term.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Searches only movies and series.
+    pub fn movies_and_series<T: Into<String>>(search_term: T) -> Self {
+        Self {
+            include_item_types: vec!["Movie".to_string(), "Series".to_string()],
+            ..Self::new(search_term)
+        }
+    }
+}
+
+impl JellyfinClient {
+    /// Searches the server for items, people, genres, studios and artists matching a term.
+    pub async fn search_hints(&self, query: SearchQuery) -> Result<SearchHints> {
+        let req = self
+            .client
+            .get(format!("{}Search/Hints", self.url))
+            .query(&query)
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+}
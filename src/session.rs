@@ -1,3 +1,4 @@
+use super::err::Result;
 use serde_derive::Deserialize;
 use serde_derive::Serialize;
 use serde_json::Value;
@@ -5,6 +6,8 @@ use serde_json::Value;
 use crate::items::FullNowPlayingItem;
 use crate::items::MediaItem;
 use crate::items::NowPlayingQueue;
+use crate::err::JellyfinError;
+use crate::JellyfinClient;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
@@ -141,6 +144,7 @@ pub struct Identification {
 pub struct Header {
     pub name: String,    
     pub value: String,    
+    #[serde(rename = "Match")]
     pub match_field: String
 }
 
@@ -157,6 +161,7 @@ pub struct DirectPlayProfile {
     pub container: String,    
     pub audio_codec: String,    
     pub video_codec: String,    
+    #[serde(rename = "Type")]
     pub type_field: String
 }
 
@@ -164,6 +169,7 @@ pub struct DirectPlayProfile {
 #[serde(rename_all = "PascalCase")]
 pub struct TranscodingProfile {
     pub container: String,    
+    #[serde(rename = "Type")]
     pub type_field: String,    
     pub video_codec: String,    
     pub audio_codec: String,    
@@ -184,6 +190,7 @@ pub struct TranscodingProfile {
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ContainerProfile {
+    #[serde(rename = "Type")]
     pub type_field: String,    
     pub conditions: Vec<Value>,    
     pub container: String
@@ -192,6 +199,7 @@ pub struct ContainerProfile {
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct CodecProfile {
+    #[serde(rename = "Type")]
     pub type_field: String,    
     pub conditions: Vec<Value>,    
     pub apply_conditions: Vec<Value>,    
@@ -205,6 +213,7 @@ pub struct ResponseProfile {
     pub container: String,    
     pub audio_codec: String,    
     pub video_codec: String,    
+    #[serde(rename = "Type")]
     pub type_field: String,    
     pub org_pn: String,    
     pub mime_type: String,    
@@ -237,4 +246,101 @@ pub struct TranscodingInfo {
     pub audio_channels: i64,    
     pub hardware_acceleration_type: String,    
     pub transcode_reasons: Vec<String>
-}
\ No newline at end of file
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct PostCapabilitiesQuery {
+    id: Option<String>,
+    playable_media_types: String,
+    supported_commands: String,
+    supports_media_control: bool,
+    supports_sync: bool,
+    supports_persistent_identifier: bool,
+}
+
+impl JellyfinClient {
+    /// Reports the capabilities of the current session to the server.
+    /// * `id` The session id, defaults to the session of the current auth
+    /// * `playable_media_types` The media types this client can play, ie. "Audio" or "Video"
+    /// * `supported_commands` The general commands this client can respond to
+    pub async fn post_capabilities(
+        &self,
+        id: Option<String>,
+        playable_media_types: Vec<String>,
+        supported_commands: Vec<String>,
+        supports_media_control: bool,
+        supports_sync: bool,
+        supports_persistent_identifier: bool,
+    ) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Sessions/Capabilities", self.url))
+            .query(&PostCapabilitiesQuery {
+                id,
+                playable_media_types: playable_media_types.join(","),
+                supported_commands: supported_commands.join(","),
+                supports_media_control,
+                supports_sync,
+                supports_persistent_identifier,
+            })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Reports the full capabilities of the current session, including the `DeviceProfile`, to the server.
+    pub async fn post_full_capabilities(&self, capabilities: Capabilities) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Sessions/Capabilities/Full", self.url))
+            .json(&capabilities)
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serializes_profile_type() {
+        let profile = DirectPlayProfile {
+            container: "mkv".to_string(),
+            type_field: "Video".to_string(),
+            ..Default::default()
+        };
+        let value = serde_json::to_value(&profile).unwrap();
+
+        assert_eq!(value["Type"], "Video");
+        assert!(value.get("TypeField").is_none());
+
+        let header = Header {
+            name: "User-Agent".to_string(),
+            value: "Kodi".to_string(),
+            match_field: "Substring".to_string(),
+        };
+        let value = serde_json::to_value(&header).unwrap();
+
+        assert_eq!(value["Match"], "Substring");
+        assert!(value.get("MatchField").is_none());
+    }
+}
@@ -0,0 +1,417 @@
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+use super::err::Result;
+use futures_util::SinkExt;
+use futures_util::Stream;
+use futures_util::StreamExt;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use serde_json::Value;
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::MaybeTlsStream;
+use tokio_tungstenite::WebSocketStream;
+use url::Url;
+
+use crate::activity::ActivityLogEntry;
+use crate::err::JellyfinError;
+use crate::items::UserData;
+use crate::session::SessionInfo;
+use crate::tasks::TaskInfo;
+use crate::JellyfinClient;
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+const MIN_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+/// How long a connection has to stay up before the backoff is reset.
+const STABLE_CONNECTION: Duration = Duration::from_secs(30);
+const DEFAULT_KEEP_ALIVE: Duration = Duration::from_secs(30);
+
+/// A message pushed by the server over `/socket`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ServerMessage {
+    /// Not sent by the server, yielded every time the socket (re)connects,
+    /// after all active subscriptions have been resent.
+    Connected,
+    Play(PlayRequest),
+    Playstate(PlaystateRequest),
+    GeneralCommand(GeneralCommand),
+    Sessions(Vec<SessionInfo>),
+    ActivityLogEntry(Vec<ActivityLogEntry>),
+    LibraryChanged(LibraryUpdateInfo),
+    UserDataChanged(UserDataChangeInfo),
+    ScheduledTasksInfo(Vec<TaskInfo>),
+    /// Any message type without a typed variant.
+    Unknown { message_type: String, data: Value },
+}
+
+/// A periodic feed that can be requested from the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Subscription {
+    Sessions,
+    ActivityLogEntry,
+    ScheduledTasksInfo,
+}
+
+impl Subscription {
+    fn message_type(&self) -> &'static str {
+        match self {
+            Self::Sessions => "Sessions",
+            Self::ActivityLogEntry => "ActivityLogEntry",
+            Self::ScheduledTasksInfo => "ScheduledTasksInfo",
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PlayRequest {
+    pub item_ids: Vec<String>,
+    pub start_position_ticks: Option<i64>,
+    pub play_command: String,
+    pub controlling_user_id: String,
+    pub subtitle_stream_index: Option<i64>,
+    pub audio_stream_index: Option<i64>,
+    pub media_source_id: Option<String>,
+    pub start_index: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PlaystateRequest {
+    pub command: String,
+    pub seek_position_ticks: Option<i64>,
+    pub controlling_user_id: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GeneralCommand {
+    pub name: String,
+    pub controlling_user_id: Option<String>,
+    pub arguments: HashMap<String, String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LibraryUpdateInfo {
+    pub folders_added_to: Vec<String>,
+    pub folders_removed_from: Vec<String>,
+    pub items_added: Vec<String>,
+    pub items_removed: Vec<String>,
+    pub items_updated: Vec<String>,
+    pub collection_folders: Vec<String>,
+    pub is_empty: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UserDataChangeInfo {
+    pub user_id: String,
+    pub user_data_list: Vec<UserData>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawMessage {
+    message_type: String,
+    #[serde(default)]
+    data: Value,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct OutgoingMessage {
+    message_type: String,
+    data: Option<String>,
+}
+
+impl OutgoingMessage {
+    fn to_message(&self) -> Result<Message> {
+        Ok(Message::Text(serde_json::to_string(self)?))
+    }
+}
+
+impl ServerMessage {
+    fn from_raw(raw: RawMessage) -> Result<Self> {
+        Ok(match raw.message_type.as_str() {
+            "Play" => Self::Play(serde_json::from_value(raw.data)?),
+            "Playstate" => Self::Playstate(serde_json::from_value(raw.data)?),
+            "GeneralCommand" => Self::GeneralCommand(serde_json::from_value(raw.data)?),
+            "Sessions" => Self::Sessions(serde_json::from_value(raw.data)?),
+            "ActivityLogEntry" => Self::ActivityLogEntry(serde_json::from_value(raw.data)?),
+            "LibraryChanged" => Self::LibraryChanged(serde_json::from_value(raw.data)?),
+            "UserDataChanged" => Self::UserDataChanged(serde_json::from_value(raw.data)?),
+            "ScheduledTasksInfo" => Self::ScheduledTasksInfo(serde_json::from_value(raw.data)?),
+            _ => Self::Unknown {
+                message_type: raw.message_type,
+                data: raw.data,
+            },
+        })
+    }
+}
+
+#[derive(Debug)]
+enum SocketCommand {
+    Start(Subscription, String),
+    Stop(Subscription),
+}
+
+/// A stream of `ServerMessage`s from the server's websocket.
+///
+/// The connection is kept alive and re-established with exponential backoff if it drops.
+/// Connection and parse errors are yielded as `Err` items, the stream only ends once it is dropped.
+#[derive(Debug)]
+pub struct JellyfinSocket {
+    events: mpsc::UnboundedReceiver<Result<ServerMessage>>,
+    commands: mpsc::UnboundedSender<SocketCommand>,
+    task: JoinHandle<()>,
+}
+
+impl JellyfinSocket {
+    /// Receives the next message, returns `None` if the background task has stopped.
+    pub async fn recv(&mut self) -> Option<Result<ServerMessage>> {
+        self.events.recv().await
+    }
+
+    /// Asks the server to periodically push the given feed. The subscription is kept across reconnects.
+    /// * `initial_delay_ms` The delay before the first message
+    /// * `interval_ms` The interval between messages
+    pub fn subscribe(
+        &self,
+        subscription: Subscription,
+        initial_delay_ms: u64,
+        interval_ms: u64,
+    ) -> Result<()> {
+        self.commands
+            .send(SocketCommand::Start(
+                subscription,
+                format!("{},{}", initial_delay_ms, interval_ms),
+            ))
+            .map_err(|_| JellyfinError::SocketClosed)
+    }
+
+    /// Stops a feed previously started with `subscribe`.
+    pub fn unsubscribe(&self, subscription: Subscription) -> Result<()> {
+        self.commands
+            .send(SocketCommand::Stop(subscription))
+            .map_err(|_| JellyfinError::SocketClosed)
+    }
+}
+
+impl Stream for JellyfinSocket {
+    type Item = Result<ServerMessage>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.events.poll_recv(cx)
+    }
+}
+
+impl Drop for JellyfinSocket {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+impl JellyfinClient {
+    /// Opens the server's websocket using the current auth.
+    /// Must be called from within a tokio runtime.
+    pub async fn connect_socket(&self) -> Result<JellyfinSocket> {
+        let auth = self.auth.as_ref().ok_or(JellyfinError::AuthNotFound)?;
+        let device_name = whoami::devicename().replace(' ', "_");
+
+        let mut url = Url::parse(&format!("{}socket", self.url))?;
+        let scheme = if url.scheme() == "https" { "wss" } else { "ws" };
+        let _ = url.set_scheme(scheme);
+        url.query_pairs_mut()
+            .append_pair("api_key", &auth.access_token)
+            .append_pair("deviceId", &format!("{:x}", md5::compute(device_name)));
+
+        let (socket, _) = tokio_tungstenite::connect_async(url.as_str()).await?;
+
+        let (events_tx, events_rx) = mpsc::unbounded_channel();
+        let (commands_tx, commands_rx) = mpsc::unbounded_channel();
+        let task = tokio::spawn(run_socket(url, Some(socket), events_tx, commands_rx));
+
+        Ok(JellyfinSocket {
+            events: events_rx,
+            commands: commands_tx,
+            task,
+        })
+    }
+}
+
+async fn run_socket(
+    url: Url,
+    mut socket: Option<Socket>,
+    events: mpsc::UnboundedSender<Result<ServerMessage>>,
+    mut commands: mpsc::UnboundedReceiver<SocketCommand>,
+) {
+    let mut subscriptions: Vec<(Subscription, String)> = Vec::new();
+    let mut backoff = MIN_BACKOFF;
+
+    loop {
+        let mut ws = match socket.take() {
+            Some(ws) => ws,
+            None => match tokio_tungstenite::connect_async(url.as_str()).await {
+                Ok((ws, _)) => ws,
+                Err(e) => {
+                    if events.send(Err(e.into())).is_err() {
+                        return;
+                    }
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(MAX_BACKOFF);
+                    continue;
+                }
+            },
+        };
+        let connected_at = Instant::now();
+
+        let mut resent = true;
+        for (subscription, data) in &subscriptions {
+            if let Err(e) = send(&mut ws, start_message(*subscription, data)).await {
+                let _ = events.send(Err(e));
+                resent = false;
+                break;
+            }
+        }
+
+        if resent {
+            if events.send(Ok(ServerMessage::Connected)).is_err() {
+                return;
+            }
+
+            let mut keep_alive = keep_alive_interval(DEFAULT_KEEP_ALIVE);
+            loop {
+                tokio::select! {
+                    msg = ws.next() => match msg {
+                        Some(Ok(Message::Text(text))) => {
+                            let event = match serde_json::from_str::<RawMessage>(&text) {
+                                Ok(raw) if raw.message_type == "ForceKeepAlive" => {
+                                    let timeout = raw.data.as_u64().unwrap_or(DEFAULT_KEEP_ALIVE.as_secs() * 2);
+                                    keep_alive = keep_alive_interval(Duration::from_secs((timeout / 2).max(1)));
+                                    if send(&mut ws, keep_alive_message()).await.is_err() {
+                                        break;
+                                    }
+                                    continue;
+                                }
+                                Ok(raw) if raw.message_type == "KeepAlive" => continue,
+                                Ok(raw) => ServerMessage::from_raw(raw),
+                                Err(e) => Err(e.into()),
+                            };
+                            if events.send(event).is_err() {
+                                return;
+                            }
+                        }
+                        Some(Ok(Message::Close(_))) | None => break,
+                        Some(Ok(_)) => {}
+                        Some(Err(e)) => {
+                            if events.send(Err(e.into())).is_err() {
+                                return;
+                            }
+                            break;
+                        }
+                    },
+                    _ = keep_alive.tick() => {
+                        if send(&mut ws, keep_alive_message()).await.is_err() {
+                            break;
+                        }
+                    }
+                    command = commands.recv() => {
+                        let message = match command {
+                            Some(SocketCommand::Start(subscription, data)) => {
+                                let message = start_message(subscription, &data);
+                                subscriptions.retain(|(s, _)| *s != subscription);
+                                subscriptions.push((subscription, data));
+                                message
+                            }
+                            Some(SocketCommand::Stop(subscription)) => {
+                                subscriptions.retain(|(s, _)| *s != subscription);
+                                OutgoingMessage {
+                                    message_type: format!("{}Stop", subscription.message_type()),
+                                    data: None,
+                                }
+                            }
+                            None => {
+                                let _ = ws.close(None).await;
+                                return;
+                            }
+                        };
+                        if send(&mut ws, message).await.is_err() {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        // A server that accepts the connection and closes it right away shouldn't be retried every second.
+        if connected_at.elapsed() >= STABLE_CONNECTION {
+            backoff = MIN_BACKOFF;
+        }
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+}
+
+async fn send(ws: &mut Socket, message: OutgoingMessage) -> Result<()> {
+    ws.send(message.to_message()?).await?;
+    Ok(())
+}
+
+fn start_message(subscription: Subscription, data: &str) -> OutgoingMessage {
+    OutgoingMessage {
+        message_type: format!("{}Start", subscription.message_type()),
+        data: Some(data.to_string()),
+    }
+}
+
+fn keep_alive_message() -> OutgoingMessage {
+    OutgoingMessage {
+        message_type: "KeepAlive".to_string(),
+        data: None,
+    }
+}
+
+fn keep_alive_interval(period: Duration) -> tokio::time::Interval {
+    tokio::time::interval_at(Instant::now() + period, period)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_server_messages() {
+        let raw: RawMessage = serde_json::from_str(
+            r#"{"MessageType":"Playstate","MessageId":"1","Data":{"Command":"Seek","SeekPositionTicks":100,"ControllingUserId":null}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            ServerMessage::from_raw(raw).unwrap(),
+            ServerMessage::Playstate(PlaystateRequest {
+                command: "Seek".to_string(),
+                seek_position_ticks: Some(100),
+                controlling_user_id: None,
+            })
+        );
+
+        let raw: RawMessage =
+            serde_json::from_str(r#"{"MessageType":"ServerRestarting"}"#).unwrap();
+        assert_eq!(
+            ServerMessage::from_raw(raw).unwrap(),
+            ServerMessage::Unknown {
+                message_type: "ServerRestarting".to_string(),
+                data: Value::Null,
+            }
+        );
+    }
+}
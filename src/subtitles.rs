@@ -0,0 +1,202 @@
+use super::err::Result;
+use base64::Engine;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use serde_json::json;
+
+use crate::err::JellyfinError;
+use crate::JellyfinClient;
+
+/// A text subtitle format the server can convert subtitle streams to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubtitleFormat {
+    Srt,
+    Vtt,
+    Ass,
+    Ssa,
+}
+
+impl SubtitleFormat {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Srt => "srt",
+            Self::Vtt => "vtt",
+            Self::Ass => "ass",
+            Self::Ssa => "ssa",
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct RemoteSubtitleInfo {
+    #[serde(rename = "ThreeLetterISOLanguageName")]
+    pub three_letter_iso_language_name: Option<String>,
+    pub id: String,
+    pub provider_name: String,
+    pub name: Option<String>,
+    pub format: Option<String>,
+    pub author: Option<String>,
+    pub comment: Option<String>,
+    pub date_created: Option<String>,
+    pub community_rating: Option<f64>,
+    pub download_count: Option<i64>,
+    pub is_hash_match: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SearchRemoteSubtitlesQuery {
+    is_perfect_match: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetSubtitleQuery {
+    end_position_ticks: Option<i64>,
+}
+
+impl JellyfinClient {
+    /// Searches subtitle providers for subtitles of an item.
+    /// * `language` The three letter ISO code of the language, ie. "eng"
+    /// * `is_perfect_match` Only return subtitles matching the exact file
+    pub async fn search_remote_subtitles(
+        &self,
+        item_id: &str,
+        language: &str,
+        is_perfect_match: Option<bool>,
+    ) -> Result<Vec<RemoteSubtitleInfo>> {
+        let req = self
+            .client
+            .get(format!(
+                "{}Items/{}/RemoteSearch/Subtitles/{}",
+                self.url, item_id, language
+            ))
+            .query(&SearchRemoteSubtitlesQuery { is_perfect_match })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Downloads a subtitle found with `search_remote_subtitles` and adds it to the item.
+    /// * `subtitle_id` The `id` of the `RemoteSubtitleInfo`
+    pub async fn download_remote_subtitles(&self, item_id: &str, subtitle_id: &str) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!(
+                "{}Items/{}/RemoteSearch/Subtitles/{}",
+                self.url, item_id, subtitle_id
+            ))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Uploads a subtitle file for a video.
+    /// * `language` The three letter ISO code of the language, ie. "eng"
+    /// * `data` The contents of the subtitle file
+    pub async fn upload_subtitle(
+        &self,
+        item_id: &str,
+        language: &str,
+        format: SubtitleFormat,
+        is_forced: bool,
+        data: &[u8],
+    ) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}Videos/{}/Subtitles", self.url, item_id))
+            .json(&json!({
+                "Language": language,
+                "Format": format.as_str(),
+                "IsForced": is_forced,
+                "Data": base64::engine::general_purpose::STANDARD.encode(data)
+            }))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Deletes an external subtitle file of a video.
+    /// * `index` The `MediaStream::index` of the subtitle stream
+    pub async fn delete_subtitle(&self, item_id: &str, index: i64) -> Result<()> {
+        let _req = self
+            .client
+            .delete(format!("{}Videos/{}/Subtitles/{}", self.url, item_id, index))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Gets a subtitle stream converted to the given format.
+    /// * `index` The `MediaStream::index` of the subtitle stream
+    /// * `start_position_ticks` Only include subtitles after this position
+    /// * `end_position_ticks` Only include subtitles before this position
+    pub async fn get_subtitle(
+        &self,
+        item_id: &str,
+        media_source_id: &str,
+        index: i64,
+        format: SubtitleFormat,
+        start_position_ticks: i64,
+        end_position_ticks: Option<i64>,
+    ) -> Result<String> {
+        let req = self
+            .client
+            .get(format!(
+                "{}Videos/{}/{}/Subtitles/{}/{}/Stream.{}",
+                self.url,
+                item_id,
+                media_source_id,
+                index,
+                start_position_ticks,
+                format.as_str()
+            ))
+            .query(&GetSubtitleQuery { end_position_ticks })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(req.text().await?)
+    }
+}
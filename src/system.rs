@@ -0,0 +1,350 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use super::err::Result;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+use crate::err::JellyfinError;
+use crate::JellyfinClient;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct PublicSystemInfo {
+    pub local_address: Option<String>,
+    pub server_name: String,
+    pub version: String,
+    pub product_name: Option<String>,
+    pub operating_system: Option<String>,
+    pub id: String,
+    pub startup_wizard_completed: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct SystemInfo {
+    pub local_address: Option<String>,
+    pub server_name: String,
+    pub version: String,
+    pub product_name: Option<String>,
+    pub operating_system: Option<String>,
+    pub id: String,
+    pub startup_wizard_completed: Option<bool>,
+    pub operating_system_display_name: Option<String>,
+    pub package_name: Option<String>,
+    pub has_pending_restart: bool,
+    pub is_shutting_down: bool,
+    pub supports_library_monitor: bool,
+    pub web_socket_port_number: i64,
+    pub can_self_restart: bool,
+    pub can_launch_web_browser: bool,
+    pub program_data_path: Option<String>,
+    pub web_path: Option<String>,
+    pub items_by_name_path: Option<String>,
+    pub cache_path: Option<String>,
+    pub log_path: Option<String>,
+    pub internal_metadata_path: Option<String>,
+    pub transcoding_temp_path: Option<String>,
+    pub has_update_available: bool,
+    pub encoder_location: Option<String>,
+    pub system_architecture: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct LogFile {
+    pub date_created: String,
+    pub date_modified: String,
+    /// The size of the file in bytes
+    pub size: i64,
+    pub name: String,
+}
+
+/// The content of a log file, read in chunks as it is downloaded.
+#[derive(Debug)]
+pub struct LogFileStream {
+    res: reqwest::Response,
+}
+
+impl LogFileStream {
+    /// Reads the next chunk of the file, returns `None` once the whole file has been read.
+    pub async fn chunk(&mut self) -> Result<Option<Vec<u8>>> {
+        Ok(self.res.chunk().await?.map(|chunk| chunk.to_vec()))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetLogFileQuery {
+    name: String,
+}
+
+/// A server version, comparable so features can be gated on it.
+/// Like semver, a pre-release orders before its release, so "10.9.0-rc1" < "10.9.0".
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ServerVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    /// The pre-release suffix, ie. "rc1" for "10.9.0-rc1"
+    pub pre_release: Option<String>,
+}
+
+impl ServerVersion {
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+            pre_release: None,
+        }
+    }
+}
+
+impl Ord for ServerVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (&self.pre_release, &other.pre_release) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => compare_pre_release(a, b),
+            })
+    }
+}
+
+impl PartialOrd for ServerVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares dot separated pre-release identifiers, numeric ones numerically and before alphanumeric ones.
+fn compare_pre_release(a: &str, b: &str) -> Ordering {
+    let mut a = a.split('.');
+    let mut b = b.split('.');
+    loop {
+        let ordering = match (a.next(), b.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a), Some(b)) => match (a.parse::<u64>(), b.parse::<u64>()) {
+                (Ok(a), Ok(b)) => a.cmp(&b),
+                (Ok(_), Err(_)) => Ordering::Less,
+                (Err(_), Ok(_)) => Ordering::Greater,
+                (Err(_), Err(_)) => a.cmp(b),
+            },
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+impl FromStr for ServerVersion {
+    type Err = JellyfinError;
+
+    /// Parses versions like "10.8.13" or "10.9.0-rc1", ignoring any build metadata or fourth component.
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let without_build = s.split('+').next().unwrap_or_default();
+        let (core, pre_release) = match without_build.split_once('-') {
+            Some((core, pre_release)) => (core, Some(pre_release)),
+            None => (without_build, None),
+        };
+        if pre_release.is_some_and(str::is_empty) {
+            return Err(JellyfinError::VersionParseError(s.to_string()));
+        }
+        let mut parts = core.split('.').map(|part| part.parse::<u32>());
+        let mut next = || match parts.next() {
+            Some(Ok(part)) => Ok(part),
+            None => Ok(0),
+            Some(Err(_)) => Err(JellyfinError::VersionParseError(s.to_string())),
+        };
+        Ok(Self {
+            pre_release: pre_release.map(str::to_string),
+            ..Self::new(next()?, next()?, next()?)
+        })
+    }
+}
+
+impl fmt::Display for ServerVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(pre_release) = &self.pre_release {
+            write!(f, "-{}", pre_release)?;
+        }
+        Ok(())
+    }
+}
+
+impl PublicSystemInfo {
+    pub fn server_version(&self) -> Result<ServerVersion> {
+        self.version.parse()
+    }
+}
+
+impl SystemInfo {
+    pub fn server_version(&self) -> Result<ServerVersion> {
+        self.version.parse()
+    }
+}
+
+impl JellyfinClient {
+    /// Gets basic information about the server, doesn't require auth.
+    pub async fn get_public_system_info(&self) -> Result<PublicSystemInfo> {
+        let device_name = whoami::devicename().replace(' ', "_");
+
+        let req = self.client.get(format!(
+            "{}System/Info/Public",
+            self.url
+        ))
+            .header("X-Emby-Authorization", format!("Emby UserId=\"\", Client=\"jellyfin-rs\", Device=\"{}\", DeviceId=\"{:x}\", Version=1, Token=\"\"", device_name, md5::compute(device_name.clone())))
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    pub async fn get_system_info(&self) -> Result<SystemInfo> {
+        let req = self
+            .client
+            .get(format!("{}System/Info", self.url))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Gets the version of the server, doesn't require auth.
+    pub async fn get_server_version(&self) -> Result<ServerVersion> {
+        self.get_public_system_info().await?.server_version()
+    }
+
+    /// Checks that the server is reachable, doesn't require auth.
+    pub async fn ping(&self) -> Result<()> {
+        let _req = self
+            .client
+            .get(format!("{}System/Ping", self.url))
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
+    pub async fn restart_server(&self) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}System/Restart", self.url))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
+    pub async fn shutdown_server(&self) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}System/Shutdown", self.url))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
+    pub async fn get_log_files(&self) -> Result<Vec<LogFile>> {
+        let req = self
+            .client
+            .get(format!("{}System/Logs", self.url))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Downloads a log file.
+    /// * `name` The `LogFile::name` of the file
+    pub async fn get_log_file(&self, name: &str) -> Result<LogFileStream> {
+        let req = self
+            .client
+            .get(format!("{}System/Logs/Log", self.url))
+            .query(&GetLogFileQuery {
+                name: name.to_string(),
+            })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(LogFileStream { res: req })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_server_versions() {
+        assert_eq!("10.8.13".parse::<ServerVersion>().unwrap(), ServerVersion::new(10, 8, 13));
+        assert_eq!(
+            "10.9.0-rc1".parse::<ServerVersion>().unwrap().pre_release.as_deref(),
+            Some("rc1")
+        );
+        assert_eq!("10.10".parse::<ServerVersion>().unwrap(), ServerVersion::new(10, 10, 0));
+        assert!("".parse::<ServerVersion>().is_err());
+        assert!("unstable".parse::<ServerVersion>().is_err());
+        assert!(ServerVersion::new(10, 10, 0) > ServerVersion::new(10, 9, 11));
+    }
+
+    #[test]
+    fn orders_pre_releases_before_releases() {
+        let rc1 = "10.9.0-rc1".parse::<ServerVersion>().unwrap();
+        let rc2 = "10.9.0-rc2".parse::<ServerVersion>().unwrap();
+        let release = "10.9.0".parse::<ServerVersion>().unwrap();
+
+        assert!(rc1 < release);
+        assert!(rc1 < rc2);
+        assert!(rc2 > ServerVersion::new(10, 8, 13));
+        assert_eq!(rc1.to_string(), "10.9.0-rc1");
+    }
+}
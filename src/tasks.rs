@@ -0,0 +1,242 @@
+use super::err::Result;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+use crate::err::JellyfinError;
+use crate::JellyfinClient;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct TaskInfo {
+    pub name: String,
+    /// "Idle", "Cancelling" or "Running"
+    pub state: String,
+    pub current_progress_percentage: Option<f64>,
+    pub id: String,
+    pub last_execution_result: Option<TaskResult>,
+    pub triggers: Vec<TaskTrigger>,
+    pub description: String,
+    pub category: String,
+    pub is_hidden: bool,
+    /// A stable name for the task, ie. "RefreshLibrary"
+    pub key: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct TaskResult {
+    pub start_time_utc: String,
+    pub end_time_utc: String,
+    /// "Completed", "Failed", "Cancelled" or "Aborted"
+    pub status: String,
+    pub name: String,
+    pub key: String,
+    pub id: String,
+    pub error_message: Option<String>,
+    pub long_error_message: Option<String>,
+}
+
+/// When a scheduled task runs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "Type")]
+pub enum TaskTrigger {
+    #[serde(rename = "DailyTrigger")]
+    Daily(DailyTrigger),
+    #[serde(rename = "WeeklyTrigger")]
+    Weekly(WeeklyTrigger),
+    #[serde(rename = "IntervalTrigger")]
+    Interval(IntervalTrigger),
+    #[serde(rename = "StartupTrigger")]
+    Startup(StartupTrigger),
+    /// A trigger type without a typed variant, its fields are not kept.
+    /// The server rejects it, so it has to be removed before calling `update_task_triggers`.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DailyTrigger {
+    /// The time of day to run at, in ticks since midnight
+    pub time_of_day_ticks: i64,
+    /// Stop the task if it runs for longer than this
+    pub max_runtime_ticks: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct WeeklyTrigger {
+    /// ie. "Monday"
+    pub day_of_week: String,
+    /// The time of day to run at, in ticks since midnight
+    pub time_of_day_ticks: i64,
+    pub max_runtime_ticks: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct IntervalTrigger {
+    pub interval_ticks: i64,
+    pub max_runtime_ticks: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct StartupTrigger {
+    pub max_runtime_ticks: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetScheduledTasksQuery {
+    is_hidden: Option<bool>,
+    is_enabled: Option<bool>,
+}
+
+impl JellyfinClient {
+    pub async fn get_scheduled_tasks(
+        &self,
+        is_hidden: Option<bool>,
+        is_enabled: Option<bool>,
+    ) -> Result<Vec<TaskInfo>> {
+        let req = self
+            .client
+            .get(format!("{}ScheduledTasks", self.url))
+            .query(&GetScheduledTasksQuery {
+                is_hidden,
+                is_enabled,
+            })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Gets a task, poll it to follow the progress of a running task.
+    pub async fn get_scheduled_task(&self, task_id: &str) -> Result<TaskInfo> {
+        let req = self
+            .client
+            .get(format!("{}ScheduledTasks/{}", self.url, task_id))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    pub async fn start_scheduled_task(&self, task_id: &str) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}ScheduledTasks/Running/{}", self.url, task_id))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn stop_scheduled_task(&self, task_id: &str) -> Result<()> {
+        let _req = self
+            .client
+            .delete(format!("{}ScheduledTasks/Running/{}", self.url, task_id))
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Replaces the triggers of a task.
+    /// `TaskTrigger::Unknown` can't be sent back, any such trigger must be removed first.
+    pub async fn update_task_triggers(&self, task_id: &str, triggers: Vec<TaskTrigger>) -> Result<()> {
+        let _req = self
+            .client
+            .post(format!("{}ScheduledTasks/{}/Triggers", self.url, task_id))
+            .json(&triggers)
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trips_triggers() {
+        let json = r#"[{"Type":"IntervalTrigger","IntervalTicks":864000000000},{"Type":"WeeklyTrigger","DayOfWeek":"Sunday","TimeOfDayTicks":108000000000,"MaxRuntimeTicks":72000000000}]"#;
+        let triggers: Vec<TaskTrigger> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            triggers,
+            vec![
+                TaskTrigger::Interval(IntervalTrigger {
+                    interval_ticks: 864000000000,
+                    max_runtime_ticks: None,
+                }),
+                TaskTrigger::Weekly(WeeklyTrigger {
+                    day_of_week: "Sunday".to_string(),
+                    time_of_day_ticks: 108000000000,
+                    max_runtime_ticks: Some(72000000000),
+                }),
+            ]
+        );
+
+        let value = serde_json::to_value(&triggers).unwrap();
+        assert_eq!(value[0]["Type"], "IntervalTrigger");
+        assert_eq!(value[0]["IntervalTicks"], 864000000000i64);
+        assert_eq!(value[1]["Type"], "WeeklyTrigger");
+        assert_eq!(value[1]["DayOfWeek"], "Sunday");
+        assert_eq!(serde_json::from_value::<Vec<TaskTrigger>>(value).unwrap(), triggers);
+    }
+
+    #[test]
+    fn keeps_unknown_triggers() {
+        let triggers: Vec<TaskTrigger> = serde_json::from_str(
+            r#"[{"Type":"SystemEventTrigger","SystemEvent":"WakeFromSleep"},{"Type":"StartupTrigger"}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            triggers,
+            vec![
+                TaskTrigger::Unknown,
+                TaskTrigger::Startup(StartupTrigger::default()),
+            ]
+        );
+    }
+}
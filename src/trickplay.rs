@@ -0,0 +1,161 @@
+use super::err::Result;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+use crate::err::JellyfinError;
+use crate::JellyfinClient;
+
+/// A trickplay resolution of a media source, see `MediaItem::trickplay`.
+/// Thumbnails are packed into tile images of `tile_width` by `tile_height` thumbnails.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct TrickplayInfo {
+    /// The width of a single thumbnail
+    pub width: u32,
+    /// The height of a single thumbnail
+    pub height: u32,
+    pub tile_width: u32,
+    pub tile_height: u32,
+    pub thumbnail_count: u32,
+    /// The time between thumbnails in milliseconds
+    pub interval: u32,
+    pub bandwidth: u32,
+}
+
+/// Where to find the thumbnail for a position, see `TrickplayInfo::thumbnail_at`.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrickplayThumbnail {
+    /// The index of the tile image, for `get_trickplay_tile`
+    pub tile_index: u32,
+    /// The offset of the thumbnail in the tile image, in pixels
+    pub x: u32,
+    pub y: u32,
+}
+
+impl TrickplayInfo {
+    /// Gets the thumbnail to show when scrubbing to a position.
+    pub fn thumbnail_at(&self, position_ticks: i64) -> Option<TrickplayThumbnail> {
+        let per_tile = self.tile_width * self.tile_height;
+        if self.interval == 0 || per_tile == 0 || self.thumbnail_count == 0 {
+            return None;
+        }
+
+        // There are 10,000 ticks in a millisecond.
+        let position_ms = position_ticks.max(0) / 10_000;
+        let index =
+            (position_ms / self.interval as i64).min(self.thumbnail_count as i64 - 1) as u32;
+        let in_tile = index % per_tile;
+        Some(TrickplayThumbnail {
+            tile_index: index / per_tile,
+            x: (in_tile % self.tile_width) * self.width,
+            y: (in_tile / self.tile_width) * self.height,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TrickplayQuery {
+    media_source_id: Option<String>,
+}
+
+impl JellyfinClient {
+    /// Gets the HLS playlist of the trickplay tiles of a video.
+    /// * `width` One of the widths in `MediaItem::trickplay`
+    pub async fn get_trickplay_playlist(
+        &self,
+        item_id: &str,
+        width: u32,
+        media_source_id: Option<String>,
+    ) -> Result<String> {
+        let req = self
+            .client
+            .get(format!(
+                "{}Videos/{}/Trickplay/{}/tiles.m3u8",
+                self.url, item_id, width
+            ))
+            .query(&TrickplayQuery { media_source_id })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(req.text().await?)
+    }
+
+    /// Gets a trickplay tile image as jpeg.
+    /// * `index` The `TrickplayThumbnail::tile_index` of the tile
+    pub async fn get_trickplay_tile(
+        &self,
+        item_id: &str,
+        width: u32,
+        index: u32,
+        media_source_id: Option<String>,
+    ) -> Result<Vec<u8>> {
+        let req = self
+            .client
+            .get(format!(
+                "{}Videos/{}/Trickplay/{}/{}.jpg",
+                self.url, item_id, width, index
+            ))
+            .query(&TrickplayQuery { media_source_id })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(req.bytes().await?.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn finds_thumbnail_at_position() {
+        let info = TrickplayInfo {
+            width: 320,
+            height: 180,
+            tile_width: 10,
+            tile_height: 10,
+            thumbnail_count: 150,
+            interval: 10_000,
+            ..Default::default()
+        };
+        let ticks = |ms: i64| ms * 10_000;
+
+        assert_eq!(
+            info.thumbnail_at(ticks(5_000)),
+            Some(TrickplayThumbnail { tile_index: 0, x: 0, y: 0 })
+        );
+        assert_eq!(
+            info.thumbnail_at(ticks(120_000)),
+            Some(TrickplayThumbnail { tile_index: 0, x: 640, y: 180 })
+        );
+        assert_eq!(
+            info.thumbnail_at(ticks(1_000_000)),
+            Some(TrickplayThumbnail { tile_index: 1, x: 0, y: 0 })
+        );
+        assert_eq!(
+            info.thumbnail_at(ticks(10_000_000)),
+            Some(TrickplayThumbnail { tile_index: 1, x: 2880, y: 720 })
+        );
+        assert_eq!(
+            info.thumbnail_at(i64::MAX),
+            Some(TrickplayThumbnail { tile_index: 1, x: 2880, y: 720 })
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use super::err::Result;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+use crate::err::JellyfinError;
+use crate::items::MediaItems;
+use crate::user::User;
+use crate::JellyfinClient;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetSeasonsQuery {
+    user_id: String,
+    is_missing: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetEpisodesQuery {
+    user_id: String,
+    season: Option<i64>,
+    is_missing: Option<bool>,
+    start_item_id: Option<String>,
+    start_index: Option<u32>,
+    limit: Option<u32>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetUpcomingEpisodesQuery {
+    user_id: String,
+    parent_id: Option<String>,
+    start_index: Option<u32>,
+    limit: Option<u32>,
+}
+
+/// Missing episodes are only shown to users with `display_missing_episodes` set.
+fn is_missing_filter(user: &User) -> Option<bool> {
+    (!user.configuration.display_missing_episodes).then_some(false)
+}
+
+impl JellyfinClient {
+    /// Gets the seasons of a series.
+    /// Seasons that only contain missing episodes are left out unless the user has `display_missing_episodes` set.
+    pub async fn get_seasons(&self, user: &User, series_id: &str) -> Result<MediaItems> {
+        let req = self
+            .client
+            .get(format!("{}Shows/{}/Seasons", self.url, series_id))
+            .query(&GetSeasonsQuery {
+                user_id: user.id.clone(),
+                is_missing: is_missing_filter(user),
+            })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Gets the episodes of a series.
+    /// Missing and virtual episodes are left out unless the user has `display_missing_episodes` set.
+    /// * `season` The season number to get the episodes of, or all seasons if `None`
+    /// * `start_item_id` Skip the episodes before this one
+    pub async fn get_episodes(
+        &self,
+        user: &User,
+        series_id: &str,
+        season: Option<i64>,
+        start_item_id: Option<String>,
+        start_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<MediaItems> {
+        let req = self
+            .client
+            .get(format!("{}Shows/{}/Episodes", self.url, series_id))
+            .query(&GetEpisodesQuery {
+                user_id: user.id.clone(),
+                season,
+                is_missing: is_missing_filter(user),
+                start_item_id,
+                start_index,
+                limit,
+            })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+
+    /// Gets episodes that have not aired yet.
+    pub async fn upcoming_episodes(
+        &self,
+        user_id: &str,
+        parent_id: Option<String>,
+        start_index: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<MediaItems> {
+        let req = self
+            .client
+            .get(format!("{}Shows/Upcoming", self.url))
+            .query(&GetUpcomingEpisodesQuery {
+                user_id: user_id.to_string(),
+                parent_id,
+                start_index,
+                limit,
+            })
+            .header(
+                "X-Emby-Authorization",
+                self.auth
+                    .as_ref()
+                    .ok_or(JellyfinError::AuthNotFound)?
+                    .to_emby_header(),
+            )
+            .send()
+            .await?;
+
+        Ok(req.json().await?)
+    }
+}